@@ -0,0 +1,67 @@
+//! Thresholds for pulling a remote player towards the position the server
+//! has for it.
+
+/// Share of the remaining distance closed every frame; small to prevent jittering.
+pub const POSITION_CORRECTION_STRENGTH: f32 = 0.05;
+/// Max distance before we snap instead of interpolate.
+pub const MAX_CORRECTION_DISTANCE: f32 = 100.0;
+/// Don't correct small differences to reduce jittering.
+pub const CORRECTION_DEADBAND: f32 = 1.0;
+
+/// Frames before vertical correction teleport.
+pub const VERTICAL_DIFF_FRAME_THRESHOLD: i32 = 10;
+/// Frames before deadband teleport.
+pub const DEADBAND_FRAME_THRESHOLD: i32 = 60;
+/// Minimum vertical difference to trigger correction.
+pub const VERTICAL_DIFF_THRESHOLD: f32 = 3.0;
+
+/// What to do about a remote player `distance` away from its server position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Correction {
+    /// Close enough; leave it where it is.
+    None,
+    /// Move it a share of the way there.
+    Interpolate,
+    /// Too far off, or off for too long; put it there.
+    Snap,
+}
+
+/// `frames_off` counts consecutive frames the player was outside the deadband.
+pub fn correction_for(distance: f32, frames_off: i32) -> Correction {
+    if distance > MAX_CORRECTION_DISTANCE {
+        Correction::Snap
+    } else if distance <= CORRECTION_DEADBAND {
+        Correction::None
+    } else if frames_off > DEADBAND_FRAME_THRESHOLD {
+        Correction::Snap
+    } else {
+        Correction::Interpolate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_differences_are_left_alone() {
+        assert_eq!(correction_for(CORRECTION_DEADBAND, 1_000), Correction::None);
+    }
+
+    #[test]
+    fn far_players_snap_immediately() {
+        assert_eq!(
+            correction_for(MAX_CORRECTION_DISTANCE + 1.0, 0),
+            Correction::Snap
+        );
+    }
+
+    #[test]
+    fn players_that_stay_off_snap_eventually() {
+        assert_eq!(correction_for(10.0, 0), Correction::Interpolate);
+        assert_eq!(
+            correction_for(10.0, DEADBAND_FRAME_THRESHOLD + 1),
+            Correction::Snap
+        );
+    }
+}
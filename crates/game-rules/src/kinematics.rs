@@ -0,0 +1,128 @@
+//! Looping ping-pong movement through a list of waypoints, as baked by
+//! `scene-export` from a node's `move` animation. The server and the client
+//! both evaluate paths with these functions, so they agree on where a body
+//! is at any world time.
+
+/// Whether a path with these parameters can be evaluated at all.
+pub fn is_valid_path(waypoint_count: usize, period: f32, easing: f32) -> bool {
+    waypoint_count >= 2 && period > 0.0 && easing != 0.0
+}
+
+/// Segment a path is on at `world_seconds` into the scene clock, and the
+/// eased progress along it. Segment `i` runs from waypoint `i` to `i + 1`.
+/// Returns `None` for paths that are not valid.
+pub fn path_segment(
+    waypoint_count: usize,
+    period: f32,
+    easing: f32,
+    world_seconds: f64,
+) -> Option<(usize, f32)> {
+    if !is_valid_path(waypoint_count, period, easing) {
+        return None;
+    }
+
+    // 0..1 on the way out, 1..2 on the way back.
+    let half_period = period as f64 / 2.0;
+    let phase = world_seconds.rem_euclid(period as f64) / half_period;
+    let progress = if phase > 1.0 { 2.0 - phase } else { phase };
+
+    let segments = waypoint_count - 1;
+    let scaled = progress * segments as f64;
+    let segment = (scaled.floor() as usize).min(segments - 1);
+    let weight = ease((scaled - segment as f64) as f32, easing);
+
+    Some((segment, weight))
+}
+
+pub fn lerp(from: f32, to: f32, weight: f32) -> f32 {
+    from + (to - from) * weight
+}
+
+/// Godot's `ease()`: positive curves ease in (above 1) or out (below 1),
+/// negative ones ease in and out.
+pub fn ease(x: f32, curve: f32) -> f32 {
+    let x = x.clamp(0.0, 1.0);
+
+    if curve > 0.0 {
+        if curve < 1.0 {
+            1.0 - (1.0 - x).powf(1.0 / curve)
+        } else {
+            x.powf(curve)
+        }
+    } else if curve < 0.0 {
+        if x < 0.5 {
+            (x * 2.0).powf(-curve) * 0.5
+        } else {
+            (1.0 - (1.0 - (x - 0.5) * 2.0).powf(-curve)) * 0.5 + 0.5
+        }
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "got {actual}, expected {expected}"
+        );
+    }
+
+    #[test]
+    fn invalid_paths_have_no_segment() {
+        assert_eq!(path_segment(1, 4.0, 1.0, 0.0), None);
+        assert_eq!(path_segment(2, 0.0, 1.0, 0.0), None);
+        assert_eq!(path_segment(2, 4.0, 0.0, 0.0), None);
+    }
+
+    #[test]
+    fn linear_path_goes_out_and_back() {
+        let at = |seconds| path_segment(2, 4.0, 1.0, seconds).unwrap();
+
+        assert_eq!(at(0.0), (0, 0.0));
+        assert_eq!(at(1.0), (0, 0.5));
+        assert_eq!(at(2.0), (0, 1.0));
+        assert_eq!(at(3.0), (0, 0.5));
+        assert_eq!(at(4.0), (0, 0.0));
+    }
+
+    #[test]
+    fn longer_paths_walk_every_segment() {
+        let at = |seconds| path_segment(3, 4.0, 1.0, seconds).unwrap();
+
+        assert_eq!(at(0.5), (0, 0.5));
+        assert_eq!(at(1.5), (1, 0.5));
+        // Last waypoint, reached at the half period, stays on the last segment.
+        assert_eq!(at(2.0), (1, 1.0));
+        assert_eq!(at(3.5), (0, 0.5));
+    }
+
+    #[test]
+    fn time_before_the_clock_started_wraps() {
+        assert_eq!(
+            path_segment(2, 4.0, 1.0, -1.0),
+            path_segment(2, 4.0, 1.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn ease_matches_godot_curves() {
+        assert_close(ease(0.5, 1.0), 0.5);
+        assert_close(ease(0.5, 2.0), 0.25);
+        assert_close(ease(0.5, 0.5), 0.75);
+        assert_close(ease(0.25, -2.0), 0.125);
+        assert_close(ease(0.75, -2.0), 0.875);
+        assert_close(ease(0.5, 0.0), 0.0);
+        assert_close(ease(2.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn lerp_interpolates_between_ends() {
+        assert_close(lerp(10.0, 20.0, 0.0), 10.0);
+        assert_close(lerp(10.0, 20.0, 0.25), 12.5);
+        assert_close(lerp(10.0, 20.0, 1.0), 20.0);
+    }
+}
@@ -0,0 +1,9 @@
+//! Gameplay rules shared by the Godot client (`crates/rust`) and the
+//! SpacetimeDB module (`crates/server`). Everything here is plain Rust with
+//! no engine or database types, so both sides compute the same answers.
+
+pub mod correction;
+pub mod kinematics;
+pub mod movement;
+pub mod names;
+pub mod reach;
@@ -0,0 +1,346 @@
+//! How far a player can move. The client moves its player with these
+//! parameters and the server checks reported positions against them.
+//! Positions use Godot's coordinate system, so y grows downwards.
+
+use std::fmt;
+
+/// Horizontal speed without any artifacts equipped.
+pub const PLAYER_SPEED: f32 = 100.0;
+/// Initial vertical velocity of a jump; negative because y grows downwards.
+pub const PLAYER_JUMP_VELOCITY: f32 = -300.0;
+/// Godot's default 2D gravity, which the player scene does not override.
+pub const GRAVITY: f32 = 980.0;
+
+/// Speed and jump velocity of one player, after item bonuses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MovementLimits {
+    pub speed: f32,
+    pub jump_velocity: f32,
+}
+
+/// Slack the server gives on top of `MovementLimits` when checking a move.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MovementAllowance {
+    /// Extra distance every check allows, for jitter and rounding.
+    pub tolerance: f32,
+    /// Extra speed a moving platform may add to a player riding it.
+    pub platform_speed: f32,
+    /// Fastest a falling player may drop.
+    pub max_fall_speed: f32,
+}
+
+/// A reported move from the last accepted position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Move {
+    pub from: (f32, f32),
+    pub to: (f32, f32),
+    /// Seconds since the last accepted position.
+    pub elapsed: f32,
+    pub is_jumping: bool,
+    /// Height the current jump started from, if the player had not landed yet.
+    pub jump_origin_y: Option<f32>,
+}
+
+/// Outcome of `MovementLimits::check`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckedMove {
+    /// `Move::to`, clamped to what the player could have reached.
+    pub position: (f32, f32),
+    /// Height the jump started from, until the player has landed.
+    pub jump_origin_y: Option<f32>,
+    pub violations: Vec<MovementViolation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MovementViolation {
+    Horizontal { distance: f32, allowed: f32 },
+    Rising { distance: f32, allowed: f32 },
+    Falling { distance: f32, allowed: f32 },
+    Climb { height: f32, allowed: f32 },
+}
+
+impl fmt::Display for MovementViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Horizontal { distance, allowed } => {
+                write!(f, "moved {distance:.1} horizontally, {allowed:.1} allowed")
+            }
+            Self::Rising { distance, allowed } => {
+                write!(f, "rose {distance:.1}, {allowed:.1} allowed")
+            }
+            Self::Falling { distance, allowed } => {
+                write!(f, "fell {distance:.1}, {allowed:.1} allowed")
+            }
+            Self::Climb { height, allowed } => {
+                write!(f, "jumped {height:.1} high, {allowed:.1} allowed")
+            }
+        }
+    }
+}
+
+impl Default for MovementLimits {
+    fn default() -> Self {
+        Self::with_bonuses(0.0, 0.0)
+    }
+}
+
+impl MovementLimits {
+    /// Limits with the summed speed and jump bonuses of the equipped
+    /// artifacts, e.g. `0.15` for 15% faster.
+    pub fn with_bonuses(speed_bonus: f32, jump_bonus: f32) -> Self {
+        Self {
+            speed: PLAYER_SPEED * (1.0 + speed_bonus),
+            jump_velocity: PLAYER_JUMP_VELOCITY * (1.0 + jump_bonus),
+        }
+    }
+
+    /// Apex of a jump above its starting point.
+    pub fn jump_height(&self) -> f32 {
+        self.jump_velocity * self.jump_velocity / (2.0 * GRAVITY)
+    }
+
+    /// Clamps `movement` to what these limits allow and lists what had to be
+    /// clamped. Only a jump lifts the player faster than a moving platform,
+    /// and gravity caps how far above its origin a jump can get.
+    pub fn check(&self, allowance: &MovementAllowance, movement: &Move) -> CheckedMove {
+        let (from_x, from_y) = movement.from;
+        let (mut to_x, mut to_y) = movement.to;
+        let mut violations = Vec::new();
+
+        let allowed =
+            (self.speed + allowance.platform_speed) * movement.elapsed + allowance.tolerance;
+        let offset_x = to_x - from_x;
+        if offset_x.abs() > allowed {
+            violations.push(MovementViolation::Horizontal {
+                distance: offset_x.abs(),
+                allowed,
+            });
+            to_x = from_x + allowed.copysign(offset_x);
+        }
+
+        let offset_y = to_y - from_y;
+        if offset_y < 0.0 {
+            let jump_speed = if movement.is_jumping {
+                -self.jump_velocity
+            } else {
+                0.0
+            };
+            let allowed =
+                (jump_speed + allowance.platform_speed) * movement.elapsed + allowance.tolerance;
+
+            if -offset_y > allowed {
+                violations.push(MovementViolation::Rising {
+                    distance: -offset_y,
+                    allowed,
+                });
+                to_y = from_y - allowed;
+            }
+        } else {
+            let allowed = allowance.max_fall_speed * movement.elapsed + allowance.tolerance;
+
+            if offset_y > allowed {
+                violations.push(MovementViolation::Falling {
+                    distance: offset_y,
+                    allowed,
+                });
+                to_y = from_y + allowed;
+            }
+        }
+
+        // A jump starts from the last position before it. Its origin is kept
+        // until the player stands still again or is back down at it,
+        // whatever `is_jumping` says in between, so a fresh jump cannot be
+        // started in mid-air.
+        let landed = !movement.is_jumping && movement.to.1 == from_y;
+        let jump_origin_y = match movement.jump_origin_y {
+            Some(origin_y) if !landed && to_y < origin_y => Some(origin_y),
+            _ if movement.is_jumping => Some(from_y),
+            _ => None,
+        };
+
+        if let Some(origin_y) = jump_origin_y {
+            let allowed = self.jump_height() + allowance.tolerance;
+            let height = origin_y - to_y;
+
+            if height > allowed {
+                violations.push(MovementViolation::Climb { height, allowed });
+                to_y = origin_y - allowed;
+            }
+        }
+
+        CheckedMove {
+            position: (to_x, to_y),
+            jump_origin_y,
+            violations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALLOWANCE: MovementAllowance = MovementAllowance {
+        tolerance: 0.0,
+        platform_speed: 0.0,
+        max_fall_speed: 1000.0,
+    };
+
+    fn movement(to: (f32, f32), elapsed: f32, is_jumping: bool) -> Move {
+        Move {
+            from: (0.0, 0.0),
+            to,
+            elapsed,
+            is_jumping,
+            jump_origin_y: None,
+        }
+    }
+
+    #[test]
+    fn bonuses_scale_the_base_values() {
+        let limits = MovementLimits::with_bonuses(0.15, 0.2);
+
+        assert!((limits.speed - 115.0).abs() < 1e-4);
+        assert!((limits.jump_velocity + 360.0).abs() < 1e-4);
+        assert_eq!(MovementLimits::default().speed, PLAYER_SPEED);
+    }
+
+    #[test]
+    fn jump_height_follows_from_gravity() {
+        let height = MovementLimits::default().jump_height();
+
+        assert!((height - 300.0 * 300.0 / (2.0 * GRAVITY)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn plausible_moves_pass_unchanged() {
+        let limits = MovementLimits::default();
+        let checked = limits.check(&ALLOWANCE, &movement((50.0, -20.0), 0.5, true));
+
+        assert_eq!(checked.position, (50.0, -20.0));
+        assert_eq!(checked.jump_origin_y, Some(0.0));
+        assert!(checked.violations.is_empty());
+    }
+
+    #[test]
+    fn running_too_fast_is_clamped() {
+        let limits = MovementLimits::default();
+        let checked = limits.check(&ALLOWANCE, &movement((-80.0, 0.0), 0.5, false));
+
+        assert_eq!(checked.position, (-50.0, 0.0));
+        assert_eq!(
+            checked.violations,
+            vec![MovementViolation::Horizontal {
+                distance: 80.0,
+                allowed: 50.0
+            }]
+        );
+    }
+
+    #[test]
+    fn rising_without_jumping_is_clamped() {
+        let limits = MovementLimits::default();
+        let checked = limits.check(&ALLOWANCE, &movement((0.0, -10.0), 0.1, false));
+
+        assert_eq!(checked.position, (0.0, 0.0));
+        assert!(matches!(
+            checked.violations[..],
+            [MovementViolation::Rising { .. }]
+        ));
+    }
+
+    #[test]
+    fn jumping_higher_than_gravity_allows_is_clamped() {
+        let limits = MovementLimits::default();
+        let mut reported = movement((0.0, -100.0), 0.5, true);
+        reported.from = (0.0, -40.0);
+        reported.jump_origin_y = Some(0.0);
+
+        let checked = limits.check(&ALLOWANCE, &reported);
+
+        assert_eq!(checked.jump_origin_y, Some(0.0));
+        assert!((checked.position.1 + limits.jump_height()).abs() < 1e-4);
+        assert!(matches!(
+            checked.violations[..],
+            [MovementViolation::Climb { .. }]
+        ));
+    }
+
+    #[test]
+    fn toggling_is_jumping_keeps_the_jump_origin() {
+        let limits = MovementLimits::default();
+        let mut origin = None;
+        let mut from = (0.0, 0.0);
+
+        // Rising, then claiming to have stopped jumping near the apex, then
+        // jumping again, all without touching the ground.
+        for (to, is_jumping) in [
+            ((0.0, -30.0), true),
+            ((0.0, -40.0), false),
+            ((0.0, -44.0), false),
+            ((0.0, -80.0), true),
+            ((0.0, -120.0), true),
+        ] {
+            let checked = limits.check(
+                &ALLOWANCE,
+                &Move {
+                    from,
+                    to,
+                    elapsed: 0.2,
+                    is_jumping,
+                    jump_origin_y: origin,
+                },
+            );
+
+            assert_eq!(checked.jump_origin_y, Some(0.0));
+            assert!(checked.position.1 >= -limits.jump_height() - 1e-4);
+
+            origin = checked.jump_origin_y;
+            from = checked.position;
+        }
+    }
+
+    #[test]
+    fn landing_clears_the_jump_origin() {
+        let limits = MovementLimits::default();
+        let mut reported = movement((0.0, -30.0), 0.2, false);
+        reported.from = (0.0, -30.0);
+        reported.jump_origin_y = Some(0.0);
+
+        let checked = limits.check(&ALLOWANCE, &reported);
+        assert_eq!(checked.jump_origin_y, None);
+
+        let mut back_down = movement((0.0, 0.0), 0.2, false);
+        back_down.from = (0.0, -30.0);
+        back_down.jump_origin_y = Some(0.0);
+
+        let checked = limits.check(&ALLOWANCE, &back_down);
+        assert_eq!(checked.jump_origin_y, None);
+    }
+
+    #[test]
+    fn falling_faster_than_the_cap_is_clamped() {
+        let limits = MovementLimits::default();
+        let checked = limits.check(&ALLOWANCE, &movement((0.0, 300.0), 0.1, false));
+
+        assert_eq!(checked.position, (0.0, 100.0));
+        assert!(matches!(
+            checked.violations[..],
+            [MovementViolation::Falling { .. }]
+        ));
+    }
+
+    #[test]
+    fn allowance_widens_every_limit() {
+        let limits = MovementLimits::default();
+        let allowance = MovementAllowance {
+            tolerance: 5.0,
+            platform_speed: 50.0,
+            ..ALLOWANCE
+        };
+
+        let checked = limits.check(&allowance, &movement((79.0, -9.0), 0.5, false));
+
+        assert!(checked.violations.is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+//! Player name rules, checked by the login screen before connecting and
+//! enforced by `register_player`.
+
+use std::fmt;
+
+pub const MAX_NAME_LENGTH: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameError {
+    Empty,
+    TooLong,
+}
+
+impl fmt::Display for NameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Name cannot be empty"),
+            Self::TooLong => write!(f, "Name too long (max {MAX_NAME_LENGTH} characters)"),
+        }
+    }
+}
+
+/// Returns the name as it is stored, without surrounding whitespace.
+pub fn validate_player_name(name: &str) -> Result<&str, NameError> {
+    let name = name.trim();
+
+    if name.is_empty() {
+        return Err(NameError::Empty);
+    }
+
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(NameError::TooLong);
+    }
+
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_trimmed() {
+        assert_eq!(validate_player_name("  kik \n"), Ok("kik"));
+    }
+
+    #[test]
+    fn blank_names_are_rejected() {
+        assert_eq!(validate_player_name(""), Err(NameError::Empty));
+        assert_eq!(validate_player_name("   "), Err(NameError::Empty));
+    }
+
+    #[test]
+    fn length_is_counted_in_characters_after_trimming() {
+        let longest = "ü".repeat(MAX_NAME_LENGTH);
+
+        assert_eq!(
+            validate_player_name(&format!(" {longest} ")),
+            Ok(&longest[..])
+        );
+        assert_eq!(
+            validate_player_name(&format!("{longest}a")),
+            Err(NameError::TooLong)
+        );
+    }
+}
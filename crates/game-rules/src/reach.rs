@@ -0,0 +1,36 @@
+//! How close a player has to be to something to interact with it. The
+//! server checks against the last position it has for the player, so the
+//! trigger areas in the Godot scenes should not reach further than these.
+
+/// Picking up an item another player dropped.
+pub const DROPPED_ITEM_PICKUP_RADIUS: f32 = 24.0;
+/// Opening a treasure chest.
+pub const CHEST_OPEN_RADIUS: f32 = 24.0;
+/// Making a checkpoint the respawn point.
+pub const CHECKPOINT_ACTIVATION_RADIUS: f32 = 32.0;
+/// Completing a scene at its goal.
+pub const GOAL_RADIUS: f32 = 32.0;
+
+pub fn distance(from: (f32, f32), to: (f32, f32)) -> f32 {
+    (to.0 - from.0).hypot(to.1 - from.1)
+}
+
+pub fn is_within_reach(from: (f32, f32), to: (f32, f32), radius: f32) -> bool {
+    distance(from, to) <= radius
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_is_euclidean() {
+        assert_eq!(distance((1.0, 2.0), (4.0, 6.0)), 5.0);
+    }
+
+    #[test]
+    fn the_radius_itself_is_in_reach() {
+        assert!(is_within_reach((0.0, 0.0), (0.0, 24.0), 24.0));
+        assert!(!is_within_reach((0.0, 0.0), (0.0, 24.5), 24.0));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{Checkpoint, DbConnection, LocalPlayerNode, SpacetimeDBManager, activate_checkpoint};
+
+use godot::classes::{Area2D, IArea2D, Polygon2D};
+use godot::prelude::*;
+
+use spacetimedb_sdk::DbContext;
+
+const INACTIVE_COLOR: Color = Color::from_rgb(0.6, 0.6, 0.6);
+const ACTIVE_COLOR: Color = Color::from_rgb(0.2, 0.8, 0.3);
+/// How long the flag grows and settles back after activation.
+const ACTIVATION_SECONDS: f64 = 0.3;
+const ACTIVATION_SCALE: f32 = 0.5;
+
+/// Client view of a server `checkpoint` row. Walking through it asks the
+/// server to make it the local player's respawn point; the flag turns green
+/// once the player row says so.
+#[derive(GodotClass)]
+#[class(base=Area2D)]
+pub struct CheckpointArea {
+    checkpoint_id: Option<u64>,
+    active: bool,
+    /// Seconds left of the activation pulse.
+    activation_remaining: f64,
+    flag: Option<Gd<Polygon2D>>,
+
+    #[base]
+    base: Base<Area2D>,
+}
+
+#[godot_api]
+impl IArea2D for CheckpointArea {
+    fn init(base: Base<Area2D>) -> Self {
+        Self {
+            checkpoint_id: None,
+            active: false,
+            activation_remaining: 0.0,
+            flag: None,
+            base,
+        }
+    }
+
+    fn ready(&mut self) {
+        self.flag = self.base().try_get_node_as::<Polygon2D>("Flag");
+        if self.flag.is_none() {
+            godot_error!("Could not find Flag node");
+        }
+
+        let callable = self.base().callable("on_body_entered");
+        self.base_mut().connect("body_entered", &callable);
+    }
+
+    fn process(&mut self, delta: f64) {
+        self.update_active();
+        self.process_activation(delta);
+    }
+}
+
+#[godot_api]
+impl CheckpointArea {
+    #[func]
+    fn on_body_entered(&mut self, body: Gd<Node2D>) {
+        if self.active || body.try_cast::<LocalPlayerNode>().is_err() {
+            return;
+        }
+
+        let Some(checkpoint_id) = self.checkpoint_id else {
+            return;
+        };
+
+        let Some(connection) = SpacetimeDBManager::get_read_connection() else {
+            godot_error!("Could not get database connection!");
+            return;
+        };
+
+        if let Err(e) = connection.activate_checkpoint(checkpoint_id) {
+            godot_error!("Failed to activate checkpoint {}: {}", checkpoint_id, e);
+        }
+    }
+}
+
+impl CheckpointArea {
+    pub fn setup_multiplayer(connection: &DbConnection) {
+        connection
+            .subscription_builder()
+            .subscribe("SELECT * FROM checkpoint");
+
+        connection
+            .reducers
+            .on_activate_checkpoint(|ctx, checkpoint_id| {
+                if ctx.event.caller_identity != ctx.identity() {
+                    return;
+                }
+
+                if let spacetimedb_sdk::Status::Failed(e) = &ctx.event.status {
+                    godot_print!("Checkpoint {} activation rejected: {}", checkpoint_id, e);
+                }
+            });
+    }
+
+    pub fn attach_checkpoint(&mut self, checkpoint: &Checkpoint) {
+        self.checkpoint_id = Some(checkpoint.checkpoint_id);
+        self.base_mut()
+            .set_position(checkpoint.position.clone().into());
+    }
+
+    fn update_active(&mut self) {
+        let Some(checkpoint_id) = self.checkpoint_id else {
+            return;
+        };
+
+        let player = match SpacetimeDBManager::get_read_connection() {
+            Some(db_manager) => db_manager.get_local_player(),
+            None => return,
+        };
+
+        let active =
+            matches!(player, Ok(Some(player)) if player.checkpoint_id == Some(checkpoint_id));
+        if active == self.active {
+            return;
+        }
+
+        self.active = active;
+        if active {
+            self.activation_remaining = ACTIVATION_SECONDS;
+        }
+
+        if let Some(flag) = &mut self.flag {
+            flag.set_color(if active { ACTIVE_COLOR } else { INACTIVE_COLOR });
+        }
+    }
+
+    fn process_activation(&mut self, delta: f64) {
+        if self.activation_remaining <= 0.0 {
+            return;
+        }
+
+        self.activation_remaining = (self.activation_remaining - delta).max(0.0);
+        let weight = (self.activation_remaining / ACTIVATION_SECONDS) as f32;
+
+        if let Some(flag) = &mut self.flag {
+            flag.set_scale(Vector2::ONE * (1.0 + ACTIVATION_SCALE * weight));
+        }
+    }
+}
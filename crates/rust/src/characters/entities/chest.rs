@@ -0,0 +1,139 @@
+use crate::{Chest, DbConnection, LocalPlayerNode, SpacetimeDBManager, open_chest};
+
+use godot::classes::{Area2D, IArea2D, Polygon2D};
+use godot::prelude::*;
+
+use spacetimedb_sdk::DbContext;
+
+/// Lid rotation once the chest is open, in radians.
+const OPENED_LID_ROTATION: f32 = -1.2;
+/// How long the lid takes to swing open.
+const OPENING_SECONDS: f64 = 0.25;
+
+/// Client view of a server `chest` row. Walking into it asks the server to
+/// open it for the local player; the lid swings open once a `chest_opening`
+/// row for the local player shows up.
+#[derive(GodotClass)]
+#[class(base=Area2D)]
+pub struct ChestNode {
+    chest_id: Option<u64>,
+    opened: bool,
+    /// Seconds left of the lid animation.
+    opening_remaining: f64,
+    lid: Option<Gd<Polygon2D>>,
+
+    #[base]
+    base: Base<Area2D>,
+}
+
+#[godot_api]
+impl IArea2D for ChestNode {
+    fn init(base: Base<Area2D>) -> Self {
+        Self {
+            chest_id: None,
+            opened: false,
+            opening_remaining: 0.0,
+            lid: None,
+            base,
+        }
+    }
+
+    fn ready(&mut self) {
+        self.lid = self.base().try_get_node_as::<Polygon2D>("Lid");
+        if self.lid.is_none() {
+            godot_error!("Could not find Lid node");
+        }
+
+        let callable = self.base().callable("on_body_entered");
+        self.base_mut().connect("body_entered", &callable);
+    }
+
+    fn process(&mut self, delta: f64) {
+        self.update_opened();
+        self.process_opening(delta);
+    }
+}
+
+#[godot_api]
+impl ChestNode {
+    #[func]
+    fn on_body_entered(&mut self, body: Gd<Node2D>) {
+        if self.opened || body.try_cast::<LocalPlayerNode>().is_err() {
+            return;
+        }
+
+        let Some(chest_id) = self.chest_id else {
+            return;
+        };
+
+        let Some(connection) = SpacetimeDBManager::get_read_connection() else {
+            godot_error!("Could not get database connection!");
+            return;
+        };
+
+        if let Err(e) = connection.open_chest(chest_id) {
+            godot_error!("Failed to open chest {}: {}", chest_id, e);
+        }
+    }
+}
+
+impl ChestNode {
+    pub fn setup_multiplayer(connection: &DbConnection) {
+        connection
+            .subscription_builder()
+            .subscribe(["SELECT * FROM chest", "SELECT * FROM chest_opening"]);
+
+        connection.reducers.on_open_chest(|ctx, chest_id| {
+            if ctx.event.caller_identity != ctx.identity() {
+                return;
+            }
+
+            if let spacetimedb_sdk::Status::Failed(e) = &ctx.event.status {
+                godot_print!("Opening chest {} rejected: {}", chest_id, e);
+            }
+        });
+    }
+
+    pub fn attach_chest(&mut self, chest: &Chest) {
+        self.chest_id = Some(chest.chest_id);
+        self.base_mut().set_position(chest.position.clone().into());
+    }
+
+    fn update_opened(&mut self) {
+        let Some(chest_id) = self.chest_id else {
+            return;
+        };
+
+        let opened = match SpacetimeDBManager::get_read_connection() {
+            Some(db_manager) => db_manager.is_chest_opened(chest_id),
+            None => return,
+        };
+
+        let opened = matches!(opened, Ok(true));
+        if opened == self.opened {
+            return;
+        }
+
+        self.opened = opened;
+        self.opening_remaining = if opened { OPENING_SECONDS } else { 0.0 };
+
+        if let Some(lid) = &mut self.lid
+            && !opened
+        {
+            lid.set_rotation(0.0);
+        }
+    }
+
+    fn process_opening(&mut self, delta: f64) {
+        if self.opening_remaining <= 0.0 {
+            return;
+        }
+
+        self.opening_remaining = (self.opening_remaining - delta).max(0.0);
+        let weight = 1.0 - (self.opening_remaining / OPENING_SECONDS) as f32;
+
+        if let Some(lid) = &mut self.lid {
+            lid.set_rotation(OPENED_LID_ROTATION * weight);
+        }
+    }
+}
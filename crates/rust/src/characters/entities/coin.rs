@@ -1,13 +1,38 @@
 use crate::*;
 
-use godot::classes::{AnimationPlayer, Area2D, IArea2D};
+use godot::classes::{AnimationPlayer, Area2D, IArea2D, PackedScene, ResourceLoader};
+use godot::obj::BaseMut;
 use godot::prelude::*;
 
-use spacetimedb_sdk::DbContext;
+use spacetimedb_sdk::{DbContext, Identity, Table, TableWithPrimaryKey};
+
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+const COIN_SCENE_PATH: &str = "res://scenes/entities/coin.tscn";
+
+lazy_static! {
+    /// Coin table changes waiting to be applied by the `MultiplayerManager`.
+    pub static ref COIN_EVENTS: Mutex<Vec<CoinEvent>> = Mutex::new(Vec::new());
+}
+
+#[derive(Clone, Debug)]
+pub enum CoinEvent {
+    /// The coin became available, either on insert or after a respawn.
+    Spawned(Coin),
+    Collected {
+        coin_id: u64,
+        collected_by: Identity,
+    },
+    Removed(u64),
+}
 
 #[derive(GodotClass)]
 #[class(base=Area2D)]
 pub struct CoinNode {
+    coin_id: u64,
+
     game_manager: Option<Gd<GameManager>>,
 
     animation_player: Option<Gd<AnimationPlayer>>,
@@ -20,6 +45,7 @@ pub struct CoinNode {
 impl IArea2D for CoinNode {
     fn init(base: Base<Area2D>) -> Self {
         Self {
+            coin_id: 0,
             game_manager: None,
             animation_player: None,
             base,
@@ -56,48 +82,115 @@ impl CoinNode {
         connection
             .subscription_builder()
             .subscribe("SELECT * FROM coin");
-    }
 
-    // pub fn spawn_object(id: u64, position: Vector2) {}
+        // After a reconnect every row is inserted again, so collected coins
+        // also clear out any stale node left from before the drop.
+        connection.db.coin().on_insert(|_ctx, coin| {
+            if coin.collected_by.is_none() {
+                Self::push_event(CoinEvent::Spawned(coin.clone()));
+            } else {
+                Self::push_event(CoinEvent::Removed(coin.coin_id));
+            }
+        });
+
+        connection.db.coin().on_update(|_ctx, old, new| {
+            match (old.collected_by, new.collected_by) {
+                (None, Some(collected_by)) => Self::push_event(CoinEvent::Collected {
+                    coin_id: new.coin_id,
+                    collected_by,
+                }),
+                (Some(_), None) => Self::push_event(CoinEvent::Spawned(new.clone())),
+                _ => {}
+            }
+        });
 
-    #[func]
-    fn on_body_entered(&mut self, body: Gd<Node2D>) {
-        let position = self.base().get_global_position();
+        connection.db.coin().on_delete(|_ctx, coin| {
+            Self::push_event(CoinEvent::Removed(coin.coin_id));
+        });
+    }
 
-        {
-            let Some(connection) = SpacetimeDBManager::get_read_connection() else {
-                godot_print!("No connection!");
-
-                return;
-            };
-
-            match connection.collect_coin_at_position(position) {
-                Ok(_) => {
-                    godot_print!(
-                        "Coin collected successfully at ({}, {})!",
-                        position.x,
-                        position.y
-                    );
-                }
-                Err(e) => {
-                    godot_error!("Failed to collect coin: {}", e);
-                    return;
-                }
-            }
-        }
+    fn push_event(event: CoinEvent) {
+        COIN_EVENTS.lock().unwrap().push(event);
+    }
 
-        let local_player = body.try_cast::<LocalPlayerNode>();
-        if local_player.is_err() {
-            self.base_mut().queue_free();
+    pub fn take_events() -> Vec<CoinEvent> {
+        std::mem::take(&mut *COIN_EVENTS.lock().unwrap())
+    }
 
-            return;
-        }
+    pub fn spawn_object(
+        mut base: BaseMut<MultiplayerManager>,
+        coin: &Coin,
+    ) -> Result<Gd<CoinNode>, RustLibError> {
+        let mut resource_loader = ResourceLoader::singleton();
+        let Some(packed_scene) = resource_loader.load(COIN_SCENE_PATH) else {
+            godot_print!("Failed to load resource at {}", COIN_SCENE_PATH);
+            return Err(RustLibError::ResourceLoadError(COIN_SCENE_PATH.to_string()));
+        };
+
+        let Ok(scene) = packed_scene.try_cast::<PackedScene>() else {
+            godot_print!("Failed to cast resource to PackedScene");
+            return Err(RustLibError::ResourceCastError(
+                COIN_SCENE_PATH.to_string(),
+                "PackedScene".to_string(),
+            ));
+        };
+
+        let Some(instance) = scene.instantiate() else {
+            godot_print!("Failed to instantiate scene");
+            return Err(RustLibError::ResourceInstantiateError(
+                COIN_SCENE_PATH.to_string(),
+            ));
+        };
+
+        let Ok(mut coin_node) = instance.try_cast::<CoinNode>() else {
+            godot_print!("Failed to cast instance to Coin");
+            return Err(RustLibError::ResourceCastError(
+                COIN_SCENE_PATH.to_string(),
+                "CoinNode".to_string(),
+            ));
+        };
+
+        coin_node.set_position(coin.position.clone().into());
+        coin_node.bind_mut().coin_id = coin.coin_id;
+        let coin_id_sn = StringName::from(coin.coin_id.to_string().as_str());
+        coin_node.set_name(&coin_id_sn);
+
+        base.add_child(&coin_node);
+
+        Ok(coin_node)
+    }
 
-        // Play pickup animation
+    /// Plays the pickup animation, which frees the node when it finishes.
+    pub fn play_pickup(&mut self) {
         if let Some(animation_player) = &mut self.animation_player {
             animation_player.play_ex().name("pickup").done();
         } else {
             godot_error!("AnimationPlayer not available to play pickup animation");
+            self.base_mut().queue_free();
+        }
+    }
+
+    #[func]
+    fn on_body_entered(&mut self, body: Gd<Node2D>) {
+        // Only the local player may claim coins; everyone sees the pickup once
+        // the server confirms it through the coin table.
+        if body.try_cast::<LocalPlayerNode>().is_err() {
+            return;
+        }
+
+        let Some(connection) = SpacetimeDBManager::get_read_connection() else {
+            godot_print!("No connection!");
+
+            return;
+        };
+
+        match connection.collect_coin(self.coin_id) {
+            Ok(_) => {
+                godot_print!("Requested pickup of coin {}", self.coin_id);
+            }
+            Err(e) => {
+                godot_error!("Failed to collect coin: {}", e);
+            }
         }
     }
 
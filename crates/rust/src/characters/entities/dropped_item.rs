@@ -0,0 +1,128 @@
+use crate::{
+    DbConnection, DroppedItem, LocalPlayerNode, MultiplayerManager, RustLibError,
+    SpacetimeDBManager, pick_up_item,
+};
+use godot::classes::{Area2D, IArea2D, PackedScene, ResourceLoader};
+use godot::obj::BaseMut;
+use godot::prelude::*;
+
+use spacetimedb_sdk::DbContext;
+
+const DROPPED_ITEM_SCENE_PATH: &str = "res://scenes/entities/dropped_item.tscn";
+
+/// A freshly dropped item lands under the player who dropped it, so it
+/// ignores them until this has passed and they walk back in.
+const PICKUP_DELAY_SECONDS: f64 = 1.0;
+
+/// Client view of a server `dropped_item` row.
+#[derive(GodotClass)]
+#[class(base=Area2D)]
+pub struct DroppedItemNode {
+    drop_id: u64,
+    pickup_delay_remaining: f64,
+
+    #[base]
+    base: Base<Area2D>,
+}
+
+#[godot_api]
+impl IArea2D for DroppedItemNode {
+    fn init(base: Base<Area2D>) -> Self {
+        Self {
+            drop_id: 0,
+            pickup_delay_remaining: PICKUP_DELAY_SECONDS,
+            base,
+        }
+    }
+
+    fn ready(&mut self) {
+        let callable = self.base().callable("on_body_entered");
+        self.base_mut().connect("body_entered", &callable);
+    }
+
+    fn process(&mut self, delta: f64) {
+        self.pickup_delay_remaining = (self.pickup_delay_remaining - delta).max(0.0);
+    }
+}
+
+#[godot_api]
+impl DroppedItemNode {
+    #[func]
+    fn on_body_entered(&mut self, body: Gd<Node2D>) {
+        if self.pickup_delay_remaining > 0.0 || body.try_cast::<LocalPlayerNode>().is_err() {
+            return;
+        }
+
+        let Some(connection) = SpacetimeDBManager::get_read_connection() else {
+            godot_error!("Could not get database connection!");
+            return;
+        };
+
+        if let Err(e) = connection.pick_up_item(self.drop_id) {
+            godot_error!("Failed to pick up item {}: {}", self.drop_id, e);
+        }
+    }
+}
+
+impl DroppedItemNode {
+    pub fn setup_multiplayer(connection: &DbConnection) {
+        connection
+            .subscription_builder()
+            .subscribe("SELECT * FROM dropped_item");
+
+        connection.reducers.on_pick_up_item(|ctx, drop_id| {
+            if ctx.event.caller_identity != ctx.identity() {
+                return;
+            }
+
+            if let spacetimedb_sdk::Status::Failed(e) = &ctx.event.status {
+                godot_print!("Pickup of item {} rejected: {}", drop_id, e);
+            }
+        });
+    }
+
+    pub fn spawn_object(
+        mut base: BaseMut<MultiplayerManager>,
+        dropped: &DroppedItem,
+    ) -> Result<Gd<DroppedItemNode>, RustLibError> {
+        let mut resource_loader = ResourceLoader::singleton();
+        let Some(packed_scene) = resource_loader.load(DROPPED_ITEM_SCENE_PATH) else {
+            godot_print!("Failed to load resource at {}", DROPPED_ITEM_SCENE_PATH);
+            return Err(RustLibError::ResourceLoadError(
+                DROPPED_ITEM_SCENE_PATH.to_string(),
+            ));
+        };
+
+        let Ok(scene) = packed_scene.try_cast::<PackedScene>() else {
+            godot_print!("Failed to cast resource to PackedScene");
+            return Err(RustLibError::ResourceCastError(
+                DROPPED_ITEM_SCENE_PATH.to_string(),
+                "PackedScene".to_string(),
+            ));
+        };
+
+        let Some(instance) = scene.instantiate() else {
+            godot_print!("Failed to instantiate scene");
+            return Err(RustLibError::ResourceInstantiateError(
+                DROPPED_ITEM_SCENE_PATH.to_string(),
+            ));
+        };
+
+        let Ok(mut dropped_node) = instance.try_cast::<DroppedItemNode>() else {
+            godot_print!("Failed to cast instance to DroppedItem");
+            return Err(RustLibError::ResourceCastError(
+                DROPPED_ITEM_SCENE_PATH.to_string(),
+                "DroppedItemNode".to_string(),
+            ));
+        };
+
+        dropped_node.set_position(dropped.position.clone().into());
+        dropped_node.bind_mut().drop_id = dropped.drop_id;
+        let drop_id_sn = StringName::from(format!("DroppedItem{}", dropped.drop_id).as_str());
+        dropped_node.set_name(&drop_id_sn);
+
+        base.add_child(&dropped_node);
+
+        Ok(dropped_node)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{DbConnection, LocalPlayerNode, SpacetimeDBManager, complete_scene};
+
+use godot::classes::{Area2D, IArea2D, Polygon2D};
+use godot::prelude::*;
+
+use spacetimedb_sdk::DbContext;
+
+const PENDING_COLOR: Color = Color::from_rgb(0.95, 0.8, 0.2);
+const COMPLETED_COLOR: Color = Color::from_rgb(0.2, 0.8, 0.3);
+
+/// End of the scene. Reaching it asks the server to complete the scene for
+/// the local player; the banner turns green once a `scene_completion` row
+/// for the current scene shows up.
+#[derive(GodotClass)]
+#[class(base=Area2D)]
+pub struct GoalArea {
+    completed: bool,
+    banner: Option<Gd<Polygon2D>>,
+
+    #[base]
+    base: Base<Area2D>,
+}
+
+#[godot_api]
+impl IArea2D for GoalArea {
+    fn init(base: Base<Area2D>) -> Self {
+        Self {
+            completed: false,
+            banner: None,
+            base,
+        }
+    }
+
+    fn ready(&mut self) {
+        self.banner = self.base().try_get_node_as::<Polygon2D>("Banner");
+        if self.banner.is_none() {
+            godot_error!("Could not find Banner node");
+        }
+
+        let callable = self.base().callable("on_body_entered");
+        self.base_mut().connect("body_entered", &callable);
+    }
+
+    fn process(&mut self, _delta: f64) {
+        self.update_completed();
+    }
+}
+
+#[godot_api]
+impl GoalArea {
+    #[func]
+    fn on_body_entered(&mut self, body: Gd<Node2D>) {
+        if self.completed || body.try_cast::<LocalPlayerNode>().is_err() {
+            return;
+        }
+
+        let Some(connection) = SpacetimeDBManager::get_read_connection() else {
+            godot_error!("Could not get database connection!");
+            return;
+        };
+
+        if let Err(e) = connection.complete_scene() {
+            godot_error!("Failed to complete scene: {}", e);
+        }
+    }
+}
+
+impl GoalArea {
+    pub fn setup_multiplayer(connection: &DbConnection) {
+        connection
+            .subscription_builder()
+            .subscribe("SELECT * FROM scene_completion");
+
+        connection.reducers.on_complete_scene(|ctx| {
+            if ctx.event.caller_identity != ctx.identity() {
+                return;
+            }
+
+            if let spacetimedb_sdk::Status::Failed(e) = &ctx.event.status {
+                godot_print!("Scene completion rejected: {}", e);
+            }
+        });
+    }
+
+    fn update_completed(&mut self) {
+        let completed = match SpacetimeDBManager::get_read_connection() {
+            Some(db_manager) => db_manager.is_scene_completed(),
+            None => return,
+        };
+
+        let completed = matches!(completed, Ok(true));
+        if completed == self.completed {
+            return;
+        }
+
+        self.completed = completed;
+        if let Some(banner) = &mut self.banner {
+            banner.set_color(if completed {
+                COMPLETED_COLOR
+            } else {
+                PENDING_COLOR
+            });
+        }
+    }
+}
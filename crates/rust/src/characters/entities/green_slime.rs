@@ -1,11 +1,40 @@
-use crate::DbConnection;
-use godot::classes::{AnimatableBody2D, AnimationPlayer, IAnimatableBody2D};
+use crate::{
+    DbConnection, Enemy, LocalPlayerNode, MultiplayerManager, RustLibError, SpacetimeDBManager,
+    stomp_enemy,
+};
+use godot::classes::{
+    AnimatableBody2D, AnimatedSprite2D, Area2D, IAnimatableBody2D, PackedScene, ResourceLoader,
+};
+use godot::obj::BaseMut;
 use godot::prelude::*;
 
+use spacetimedb_sdk::DbContext;
+
+const GREEN_SLIME_SCENE_PATH: &str = "res://scenes/characters/green_slime.tscn";
+
+/// How quickly the node closes the gap to the last server position, per second.
+const INTERPOLATION_RATE: f32 = 12.0;
+/// Max distance before we snap instead of interpolate
+const MAX_INTERPOLATION_DISTANCE: f32 = 64.0;
+
+/// How far green and blue drop when the hit flash starts.
+const HIT_FLASH_STRENGTH: f32 = 0.7;
+const HIT_FLASH_SECONDS: f64 = 0.25;
+const DEATH_SECONDS: f64 = 0.4;
+
+/// Client view of a server `enemy` row; the server AI decides where it goes.
 #[derive(GodotClass)]
 #[class(base=AnimatableBody2D)]
 pub struct GreenSlimeNode {
-    animation_player: Option<Gd<AnimationPlayer>>,
+    enemy_id: u64,
+    target_position: Vector2,
+    /// Health from the last applied row, to notice hits.
+    health: Option<u32>,
+    /// Seconds left of the red flash after a hit.
+    hit_flash_remaining: f64,
+    /// Seconds left of the death animation, once the row is gone.
+    death_remaining: Option<f64>,
+    sprite: Option<Gd<AnimatedSprite2D>>,
 
     #[base]
     base: Base<AnimatableBody2D>,
@@ -15,35 +44,208 @@ pub struct GreenSlimeNode {
 impl IAnimatableBody2D for GreenSlimeNode {
     fn init(base: Base<AnimatableBody2D>) -> Self {
         Self {
+            enemy_id: 0,
+            target_position: Vector2::ZERO,
+            health: None,
+            hit_flash_remaining: 0.0,
+            death_remaining: None,
+            sprite: None,
             base,
-            animation_player: None,
         }
     }
 
     fn ready(&mut self) {
-        self.animation_player = self
+        self.sprite = self
             .base()
-            .try_get_node_as::<AnimationPlayer>("AnimationPlayer");
+            .try_get_node_as::<AnimatedSprite2D>("AnimatedSprite");
+        self.target_position = self.base().get_position();
+
+        let callable = self.base().callable("on_stomp_area_entered");
+        if let Some(mut stomp_area) = self.base().try_get_node_as::<Area2D>("StompArea") {
+            stomp_area.connect("body_entered", &callable);
+        } else {
+            godot_error!("Could not find StompArea node");
+        }
     }
-}
 
-impl GreenSlimeNode {
-    pub fn setup_multiplayer(_connection: &DbConnection) {}
+    fn process(&mut self, delta: f64) {
+        self.process_hit_flash(delta);
+        self.process_death(delta);
+    }
+
+    fn physics_process(&mut self, delta: f64) {
+        if self.death_remaining.is_some() {
+            return;
+        }
+
+        let position = self.base().get_position();
+        let distance = position.distance_to(self.target_position);
+
+        let next_position = if distance > MAX_INTERPOLATION_DISTANCE {
+            self.target_position
+        } else {
+            let weight = (INTERPOLATION_RATE * delta as f32).min(1.0);
+            position.lerp(self.target_position, weight)
+        };
+
+        self.base_mut().set_position(next_position);
+    }
 }
 
 #[godot_api]
 impl GreenSlimeNode {
-    /// Synchronize slime animation based on a shared world time (in microseconds).
-    /// Uses AnimationPlayer.advance(time) so Godot handles looping/ping-pong correctly.
+    /// Landing on the slime while falling stomps it; the server decides whether it counts.
     #[func]
-    pub fn sync_based_on_time(&mut self, time_microseconds: f64) {
-        let Some(ref mut animation_player) = self.animation_player else {
+    fn on_stomp_area_entered(&mut self, body: Gd<Node2D>) {
+        if self.death_remaining.is_some() {
+            return;
+        }
+
+        let Ok(mut player) = body.try_cast::<LocalPlayerNode>() else {
+            return;
+        };
+
+        if player.get_velocity().y <= 0.0 {
+            return;
+        }
+
+        player.bind_mut().bounce();
+
+        let Some(connection) = SpacetimeDBManager::get_read_connection() else {
+            godot_error!("Could not get database connection!");
             return;
         };
 
-        // Start (or restart) the current animation at t=0, then advance by the shared time.
-        let time_seconds = time_microseconds / 1_000_000.0;
-        animation_player.call("play", &["move".to_variant()]);
-        animation_player.call("advance", &[time_seconds.to_variant()]);
+        if let Err(e) = connection.stomp_enemy(self.enemy_id) {
+            godot_error!("Failed to stomp enemy {}: {}", self.enemy_id, e);
+        }
+    }
+}
+
+impl GreenSlimeNode {
+    pub fn setup_multiplayer(connection: &DbConnection) {
+        connection
+            .subscription_builder()
+            .subscribe("SELECT * FROM enemy");
+
+        connection.reducers.on_stomp_enemy(|ctx, enemy_id| {
+            if ctx.event.caller_identity != ctx.identity() {
+                return;
+            }
+
+            if let spacetimedb_sdk::Status::Failed(e) = &ctx.event.status {
+                godot_print!("Stomp on enemy {} rejected: {}", enemy_id, e);
+            }
+        });
+    }
+
+    pub fn spawn_object(
+        mut base: BaseMut<MultiplayerManager>,
+        enemy: &Enemy,
+    ) -> Result<Gd<GreenSlimeNode>, RustLibError> {
+        let mut resource_loader = ResourceLoader::singleton();
+        let Some(packed_scene) = resource_loader.load(GREEN_SLIME_SCENE_PATH) else {
+            godot_print!("Failed to load resource at {}", GREEN_SLIME_SCENE_PATH);
+            return Err(RustLibError::ResourceLoadError(
+                GREEN_SLIME_SCENE_PATH.to_string(),
+            ));
+        };
+
+        let Ok(scene) = packed_scene.try_cast::<PackedScene>() else {
+            godot_print!("Failed to cast resource to PackedScene");
+            return Err(RustLibError::ResourceCastError(
+                GREEN_SLIME_SCENE_PATH.to_string(),
+                "PackedScene".to_string(),
+            ));
+        };
+
+        let Some(instance) = scene.instantiate() else {
+            godot_print!("Failed to instantiate scene");
+            return Err(RustLibError::ResourceInstantiateError(
+                GREEN_SLIME_SCENE_PATH.to_string(),
+            ));
+        };
+
+        let Ok(mut slime) = instance.try_cast::<GreenSlimeNode>() else {
+            godot_print!("Failed to cast instance to GreenSlime");
+            return Err(RustLibError::ResourceCastError(
+                GREEN_SLIME_SCENE_PATH.to_string(),
+                "GreenSlimeNode".to_string(),
+            ));
+        };
+
+        slime.set_position(enemy.position.clone().into());
+        slime.bind_mut().enemy_id = enemy.enemy_id;
+        let enemy_id_sn = StringName::from(format!("Enemy{}", enemy.enemy_id).as_str());
+        slime.set_name(&enemy_id_sn);
+
+        base.add_child(&slime);
+        slime.bind_mut().set_enemy_state(enemy);
+
+        Ok(slime)
+    }
+
+    pub fn get_enemy_id(&self) -> u64 {
+        self.enemy_id
+    }
+
+    /// Applies the latest server row; the node glides to the position in `physics_process`.
+    pub fn set_enemy_state(&mut self, enemy: &Enemy) {
+        self.target_position = enemy.position.clone().into();
+
+        if let Some(sprite) = &mut self.sprite {
+            sprite.set_flip_h(enemy.direction < 0);
+        }
+
+        if self.health.is_some_and(|health| enemy.health < health) {
+            self.hit_flash_remaining = HIT_FLASH_SECONDS;
+        }
+        self.health = Some(enemy.health);
+    }
+
+    /// Squashes and fades the slime out, then frees it.
+    pub fn play_death(&mut self) {
+        if self.death_remaining.is_some() {
+            return;
+        }
+        self.death_remaining = Some(DEATH_SECONDS);
+
+        for area in ["Killzone", "StompArea"] {
+            if let Some(mut area) = self.base().try_get_node_as::<Area2D>(area) {
+                area.set_deferred("monitoring", &false.to_variant());
+            }
+        }
+    }
+
+    fn process_hit_flash(&mut self, delta: f64) {
+        if self.hit_flash_remaining <= 0.0 {
+            return;
+        }
+
+        self.hit_flash_remaining = (self.hit_flash_remaining - delta).max(0.0);
+        let weight = (self.hit_flash_remaining / HIT_FLASH_SECONDS) as f32;
+        let fade = 1.0 - HIT_FLASH_STRENGTH * weight;
+
+        if let Some(sprite) = &mut self.sprite {
+            sprite.set_modulate(Color::from_rgb(1.0, fade, fade));
+        }
+    }
+
+    fn process_death(&mut self, delta: f64) {
+        let Some(remaining) = self.death_remaining else {
+            return;
+        };
+
+        let remaining = remaining - delta;
+        if remaining <= 0.0 {
+            self.base_mut().queue_free();
+            return;
+        }
+        self.death_remaining = Some(remaining);
+
+        let progress = 1.0 - (remaining / DEATH_SECONDS) as f32;
+        let mut base = self.base_mut();
+        base.set_scale(Vector2::new(1.0 + 0.4 * progress, 1.0 - 0.8 * progress));
+        base.set_modulate(Color::from_rgba(1.0, 1.0, 1.0, 1.0 - progress));
     }
 }
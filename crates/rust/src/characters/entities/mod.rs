@@ -1,7 +1,17 @@
+mod checkpoint;
+mod chest;
 mod coin;
+mod dropped_item;
+mod goal;
 mod green_slime;
 mod platform;
+mod portal;
 
+pub use checkpoint::*;
+pub use chest::*;
 pub use coin::*;
+pub use dropped_item::*;
+pub use goal::*;
 pub use green_slime::*;
 pub use platform::*;
+pub use portal::*;
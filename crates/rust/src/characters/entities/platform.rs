@@ -1,11 +1,27 @@
-use crate::DbConnection;
+use crate::{DbConnection, MovementPath, Platform, get_current_world_time};
 use godot::classes::{AnimatableBody2D, AnimationPlayer, IAnimatableBody2D};
 use godot::prelude::*;
 
+use spacetimedb_sdk::DbContext;
+
+/// Drift (in seconds) from the world time that is left alone.
+const DRIFT_TOLERANCE: f64 = 0.02;
+/// Drift (in seconds) beyond which the playhead is snapped instead of eased.
+const HARD_SNAP_THRESHOLD: f64 = 0.5;
+/// Time over which a drift is caught up by adjusting the playback speed.
+const CORRECTION_WINDOW: f64 = 1.0;
+const MAX_SPEED_ADJUSTMENT: f64 = 0.25;
+
+/// Moves along the server's `platform` row path. The scene's `AnimationPlayer`
+/// only previews the movement in the editor and is stopped once a path is set.
 #[derive(GodotClass)]
 #[class(base=AnimatableBody2D)]
 pub struct PlatformNode {
-    animation_player: Option<Gd<AnimationPlayer>>,
+    path: Option<MovementPath>,
+    /// Seconds along the path. It advances with the frame time and is eased
+    /// toward the world time, so clock sync corrections do not make the
+    /// platform jump.
+    playback_time: Option<f64>,
 
     #[base]
     base: Base<AnimatableBody2D>,
@@ -15,35 +31,98 @@ pub struct PlatformNode {
 impl IAnimatableBody2D for PlatformNode {
     fn init(base: Base<AnimatableBody2D>) -> Self {
         Self {
+            path: None,
+            playback_time: None,
             base,
-            animation_player: None,
         }
     }
 
-    fn ready(&mut self) {
-        self.animation_player = self
-            .base()
-            .try_get_node_as::<AnimationPlayer>("AnimationPlayer");
+    fn physics_process(&mut self, delta: f64) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let Some(time_microseconds) = get_current_world_time() else {
+            return;
+        };
+        let world_seconds = time_microseconds as f64 / 1_000_000.0;
+
+        let playback_time = match self.playback_time {
+            None => world_seconds,
+            Some(playback_time) => {
+                let advanced = playback_time + delta;
+                let drift = wrap(world_seconds - advanced, path.period as f64);
+
+                if drift.abs() > HARD_SNAP_THRESHOLD {
+                    world_seconds
+                } else if drift.abs() <= DRIFT_TOLERANCE {
+                    advanced
+                } else {
+                    let adjustment = (drift / CORRECTION_WINDOW)
+                        .clamp(-MAX_SPEED_ADJUSTMENT, MAX_SPEED_ADJUSTMENT);
+                    advanced + delta * adjustment
+                }
+            }
+        };
+
+        self.set_playback_time(playback_time);
     }
 }
 
 #[godot_api]
 impl PlatformNode {
-    /// Synchronize platform animation based on a shared world time (in microseconds).
-    /// Uses AnimationPlayer.advance(time) so Godot handles looping/ping-pong correctly.
+    /// Places the platform where its path puts it at a shared world time (in microseconds).
     #[func]
     pub fn sync_based_on_time(&mut self, time_microseconds: f64) {
-        let Some(ref mut animation_player) = self.animation_player else {
+        if self.path.is_some() {
+            self.set_playback_time(time_microseconds / 1_000_000.0);
+        }
+    }
+}
+
+impl PlatformNode {
+    pub fn setup_multiplayer(connection: &DbConnection) {
+        connection
+            .subscription_builder()
+            .subscribe("SELECT * FROM platform");
+    }
+
+    pub fn attach_platform(&mut self, platform: &Platform) {
+        if let Some(mut animation_player) = self
+            .base()
+            .try_get_node_as::<AnimationPlayer>("AnimationPlayer")
+        {
+            animation_player.stop();
+        }
+
+        self.playback_time = None;
+        match &platform.path {
+            Some(path) => self.path = Some(path.clone()),
+            None => {
+                self.path = None;
+                self.base_mut()
+                    .set_position(platform.rest_position.clone().into());
+            }
+        }
+    }
+
+    fn set_playback_time(&mut self, playback_time: f64) {
+        let Some(path) = &self.path else {
             return;
         };
 
-        // Start (or restart) the current animation at t=0, then advance by the shared time.
-        let time_seconds = time_microseconds / 1_000_000.0;
-        animation_player.call("play", &["move".to_variant()]);
-        animation_player.call("advance", &[time_seconds.to_variant()]);
+        let position = path.position_at(playback_time);
+        self.playback_time = Some(playback_time);
+        self.base_mut().set_position(position.into());
     }
 }
 
-impl PlatformNode {
-    pub fn setup_multiplayer(_connection: &DbConnection) {}
+/// Wraps a time difference into `(-period / 2, period / 2]`.
+fn wrap(difference: f64, period: f64) -> f64 {
+    let wrapped = difference.rem_euclid(period);
+    if wrapped > period / 2.0 {
+        wrapped - period
+    } else {
+        wrapped
+    }
 }
@@ -0,0 +1,82 @@
+use crate::{
+    DbConnection, LocalPlayerNode, SceneTransitionState, SpacetimeDBManager, change_scene,
+};
+use std::sync::{Arc, Mutex};
+
+use godot::classes::{Area2D, IArea2D};
+use godot::prelude::*;
+
+use spacetimedb_sdk::DbContext;
+
+/// Enters the server portal row named after this node in the current scene.
+#[derive(GodotClass)]
+#[class(base=Area2D)]
+pub struct PortalArea {
+    #[base]
+    base: Base<Area2D>,
+}
+
+#[godot_api]
+impl IArea2D for PortalArea {
+    fn init(base: Base<Area2D>) -> Self {
+        Self { base }
+    }
+
+    fn ready(&mut self) {
+        let callable = self.base().callable("on_body_entered");
+        self.base_mut().connect("body_entered", &callable);
+    }
+}
+
+#[godot_api]
+impl PortalArea {
+    pub fn setup_multiplayer(
+        connection: &DbConnection,
+        transition_state: Arc<Mutex<SceneTransitionState>>,
+    ) {
+        connection
+            .subscription_builder()
+            .subscribe("SELECT * FROM portal");
+
+        connection.reducers.on_change_scene(move |ctx, portal| {
+            if ctx.event.caller_identity != ctx.identity() {
+                return;
+            }
+
+            match &ctx.event.status {
+                spacetimedb_sdk::Status::Committed => {
+                    godot_print!("Scene change through portal {} committed", portal);
+                }
+                spacetimedb_sdk::Status::Failed(e) => {
+                    godot_print!("Scene change through portal {} failed: {}", portal, e);
+
+                    let mut state = transition_state.lock().unwrap();
+                    *state = SceneTransitionState::Idle;
+                }
+                spacetimedb_sdk::Status::OutOfEnergy => {
+                    godot_print!("Scene change failed: Out of energy");
+
+                    let mut state = transition_state.lock().unwrap();
+                    *state = SceneTransitionState::Idle;
+                }
+            }
+        });
+    }
+
+    #[func]
+    fn on_body_entered(&mut self, body: Gd<Node2D>) {
+        if body.try_cast::<LocalPlayerNode>().is_err() {
+            return;
+        }
+
+        let Some(connection) = SpacetimeDBManager::get_read_connection() else {
+            godot_error!("Could not get database connection!");
+            return;
+        };
+
+        let portal_name = self.base().get_name().to_string();
+        if let Err(e) = connection.change_scene(&portal_name) {
+            godot_error!("Failed to enter portal {}: {}", portal_name, e);
+        }
+    }
+}
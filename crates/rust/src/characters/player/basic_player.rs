@@ -1,12 +1,20 @@
+use game_rules::movement::{MovementLimits, PLAYER_JUMP_VELOCITY, PLAYER_SPEED};
 use godot::classes::AnimatedSprite2D;
 use godot::prelude::*;
 
-use crate::handle_player_animation;
+use crate::{ItemEffect, handle_death_animation, handle_player_animation, reset_death_animation};
+
+/// Seconds the death animation plays before the player shows up at the respawn point.
+pub const DEATH_ANIMATION_SECONDS: f64 = 0.8;
+/// Share of a full jump the player hops up with when dying.
+const DEATH_HOP_FACTOR: f32 = 0.6;
 
 pub struct BasicPlayer {
     pub speed: f32,
     pub jump_velocity: f32,
     pub animated_sprite: Option<Gd<AnimatedSprite2D>>,
+    /// Seconds left of the death animation.
+    pub death_remaining: Option<f64>,
 }
 
 impl Default for BasicPlayer {
@@ -18,12 +26,31 @@ impl Default for BasicPlayer {
 impl BasicPlayer {
     pub fn new() -> Self {
         Self {
-            speed: 100.0,
-            jump_velocity: -300.0,
+            speed: PLAYER_SPEED,
+            jump_velocity: PLAYER_JUMP_VELOCITY,
             animated_sprite: None,
+            death_remaining: None,
         }
     }
 
+    /// Recomputes speed and jump velocity from the base values and the
+    /// effects of the equipped artifacts, the same way the server's movement
+    /// check does.
+    pub fn apply_item_effects(&mut self, effects: &[ItemEffect]) {
+        let mut speed_bonus = 0.0;
+        let mut jump_bonus = 0.0;
+        for effect in effects {
+            match effect {
+                ItemEffect::SpeedBonus(bonus) => speed_bonus += bonus,
+                ItemEffect::JumpBonus(bonus) => jump_bonus += bonus,
+            }
+        }
+
+        let limits = MovementLimits::with_bonuses(speed_bonus, jump_bonus);
+        self.speed = limits.speed;
+        self.jump_velocity = limits.jump_velocity;
+    }
+
     pub fn handle_jump(&self, velocity: &mut Vector2) {
         velocity.y = self.jump_velocity;
     }
@@ -42,4 +69,42 @@ impl BasicPlayer {
             handle_player_animation(animated_sprite, direction, is_on_floor);
         }
     }
+
+    pub fn is_dying(&self) -> bool {
+        self.death_remaining.is_some()
+    }
+
+    /// Starts the death hop; the caller disables collisions until it ends.
+    pub fn start_death(&mut self, velocity: &mut Vector2) {
+        self.death_remaining = Some(DEATH_ANIMATION_SECONDS);
+
+        velocity.x = 0.0;
+        velocity.y = self.jump_velocity * DEATH_HOP_FACTOR;
+    }
+
+    /// Advances the death animation, returning true on the frame it finishes.
+    pub fn process_death(&mut self, delta: f64) -> bool {
+        let Some(remaining) = self.death_remaining else {
+            return false;
+        };
+
+        let remaining = remaining - delta;
+        let Some(animated_sprite) = &mut self.animated_sprite else {
+            self.death_remaining = (remaining > 0.0).then_some(remaining);
+            return remaining <= 0.0;
+        };
+
+        if remaining <= 0.0 {
+            self.death_remaining = None;
+            reset_death_animation(animated_sprite);
+
+            return true;
+        }
+
+        self.death_remaining = Some(remaining);
+        let progress = 1.0 - (remaining / DEATH_ANIMATION_SECONDS) as f32;
+        handle_death_animation(animated_sprite, progress);
+
+        false
+    }
 }
@@ -0,0 +1,69 @@
+use std::collections::VecDeque;
+
+use godot::prelude::*;
+
+/// Inputs kept for replaying; two seconds at the project's 50 physics ticks
+/// per second.
+const MAX_PENDING_INPUTS: usize = 100;
+
+/// One physics frame of local input and where it left the player.
+#[derive(Clone, Debug)]
+pub struct PredictedInput {
+    /// Sequence the resulting state was uploaded under; most frames are not uploaded.
+    pub sequence: Option<u32>,
+    pub direction: f32,
+    pub jump_pressed: bool,
+    pub delta: f64,
+    pub position: Vector2,
+    pub velocity: Vector2,
+}
+
+/// Inputs the local player has applied since the last state the server echoed back.
+#[derive(Default, Debug)]
+pub struct InputHistory {
+    inputs: VecDeque<PredictedInput>,
+    /// Latest sequence passed to `acknowledge`.
+    acknowledged: u32,
+}
+
+impl InputHistory {
+    pub fn push(&mut self, input: PredictedInput) {
+        if self.inputs.len() == MAX_PENDING_INPUTS {
+            self.inputs.pop_front();
+        }
+
+        self.inputs.push_back(input);
+    }
+
+    /// Forgets every input up to the one uploaded as `sequence` and returns
+    /// that one, if it was still kept. Sequences seen before are ignored, as
+    /// the same echo is read every frame.
+    pub fn acknowledge(&mut self, sequence: u32) -> Option<PredictedInput> {
+        if sequence <= self.acknowledged {
+            return None;
+        }
+
+        self.acknowledged = sequence;
+
+        while let Some(input) = self.inputs.front() {
+            if input.sequence.is_some_and(|sent| sent > sequence) {
+                break;
+            }
+
+            let input = self.inputs.pop_front()?;
+            if input.sequence == Some(sequence) {
+                return Some(input);
+            }
+        }
+
+        None
+    }
+
+    pub fn pending_mut(&mut self) -> impl Iterator<Item = &mut PredictedInput> {
+        self.inputs.iter_mut()
+    }
+
+    pub fn clear(&mut self) {
+        self.inputs.clear();
+    }
+}
@@ -1,19 +1,42 @@
-use super::BasicPlayer;
+use super::{BasicPlayer, InputHistory, PredictedInput, SendPolicy, UploadStats};
 use std::sync::{Arc, Mutex};
 
 use crate::{
     DbConnection, DbPlayerState, DbVector2, RegistrationState, SpacetimeDBManager, register_player,
 };
 
-use godot::classes::{AnimatedSprite2D, CharacterBody2D, ICharacterBody2D, Input};
+use godot::classes::{
+    AnimatedSprite2D, CharacterBody2D, CollisionShape2D, ICharacterBody2D, Input,
+};
 use godot::prelude::*;
 
 use spacetimedb_sdk::DbContext;
 
+/// Share of a full jump the player bounces off a stomped enemy with.
+const STOMP_BOUNCE_FACTOR: f32 = 0.7;
+/// Distance between a predicted and an acknowledged position that counts as a server correction.
+const RECONCILIATION_THRESHOLD: f32 = 0.5;
+
 #[derive(GodotClass)]
 #[class(base=CharacterBody2D)]
 pub struct LocalPlayerNode {
     basic_player: BasicPlayer,
+    /// Where the server respawned us, applied once the death animation ends.
+    respawn_position: Vector2,
+    /// Inputs applied since the last one the server acknowledged.
+    input_history: InputHistory,
+
+    /// Most state uploads per second for position changes alone.
+    #[export]
+    max_send_rate: f64,
+    /// Seconds between uploads while nothing changes.
+    #[export]
+    heartbeat_interval: f64,
+    /// Distance from the last uploaded position that is worth an upload.
+    #[export]
+    position_error_threshold: f32,
+    send_policy: SendPolicy,
+    upload_stats: UploadStats,
 
     #[base]
     base: Base<CharacterBody2D>,
@@ -22,17 +45,40 @@ pub struct LocalPlayerNode {
 #[godot_api]
 impl ICharacterBody2D for LocalPlayerNode {
     fn init(base: Base<CharacterBody2D>) -> Self {
+        let send_policy = SendPolicy::default();
+
         Self {
             basic_player: BasicPlayer::new(),
+            respawn_position: Vector2::ZERO,
+            input_history: InputHistory::default(),
+            max_send_rate: send_policy.max_rate,
+            heartbeat_interval: send_policy.heartbeat_interval,
+            position_error_threshold: send_policy.position_threshold,
+            send_policy,
+            upload_stats: UploadStats::default(),
             base,
         }
     }
 
     fn physics_process(&mut self, delta: f64) {
+        // No input and no state uploads while dying, the server already placed us at the respawn.
+        if self.basic_player.is_dying() {
+            self.process_death(delta);
+            return;
+        }
+
+        self.refresh_item_effects();
+        self.reconcile();
         self.handle_local_input(delta);
     }
 
     fn ready(&mut self) {
+        self.send_policy = SendPolicy::new(
+            self.max_send_rate,
+            self.heartbeat_interval,
+            self.position_error_threshold,
+        );
+
         if let Some(animated_sprite) = self
             .base()
             .try_get_node_as::<AnimatedSprite2D>("AnimatedSprite2D")
@@ -79,19 +125,156 @@ impl LocalPlayerNode {
             });
     }
 
+    /// Plays the death hop in place, then moves to `respawn_position`.
+    pub fn play_death(&mut self, respawn_position: Vector2) {
+        if self.basic_player.is_dying() {
+            return;
+        }
+
+        self.respawn_position = respawn_position;
+        // The respawn replaces anything we predicted.
+        self.input_history.clear();
+
+        let mut velocity = self.base().get_velocity();
+        self.basic_player.start_death(&mut velocity);
+        self.base_mut().set_velocity(velocity);
+
+        self.set_collision_disabled(true);
+    }
+
+    fn process_death(&mut self, delta: f64) {
+        if self.basic_player.process_death(delta) {
+            let respawn_position = self.respawn_position;
+            self.base_mut().set_position(respawn_position);
+            self.base_mut().set_velocity(Vector2::ZERO);
+            self.set_collision_disabled(false);
+            self.send_policy.reset();
+
+            return;
+        }
+
+        let mut velocity = self.base().get_velocity();
+        velocity.y += self.base().get_gravity().y * delta as f32;
+        self.base_mut().set_velocity(velocity);
+        self.base_mut().move_and_slide();
+    }
+
+    fn set_collision_disabled(&self, disabled: bool) {
+        if let Some(mut collision_shape) = self
+            .base()
+            .try_get_node_as::<CollisionShape2D>("CollisionShape2D")
+        {
+            collision_shape.set_deferred("disabled", &disabled.to_variant());
+        }
+    }
+
+    /// Springs the player up after landing on an enemy.
+    pub fn bounce(&mut self) {
+        let mut velocity = self.base().get_velocity();
+        velocity.y = self.basic_player.jump_velocity * STOMP_BOUNCE_FACTOR;
+        self.base_mut().set_velocity(velocity);
+    }
+
+    /// Equipped artifacts change how fast we run and how high we jump.
+    fn refresh_item_effects(&mut self) {
+        let effects = match SpacetimeDBManager::get_read_connection() {
+            Some(db_manager) => db_manager.get_equipped_effects(),
+            None => return,
+        };
+
+        if let Ok(effects) = effects {
+            self.basic_player.apply_item_effects(&effects);
+        }
+    }
+
     #[func]
     pub fn get_player_position(&self) -> Vector2 {
         self.base().get_global_position()
     }
 
+    /// Compares our predicted position with the last state the server
+    /// accepted. If the server corrected it, we move to the corrected
+    /// position and replay the inputs it has not seen yet on top of it.
+    fn reconcile(&mut self) {
+        let acknowledged = {
+            let Some(db_manager) = SpacetimeDBManager::get_read_connection() else {
+                return;
+            };
+
+            let Ok(Some(player)) = db_manager.get_local_player() else {
+                return;
+            };
+
+            player.state
+        };
+
+        let Some(predicted) = self.input_history.acknowledge(acknowledged.input_sequence) else {
+            return;
+        };
+
+        let server_position = Vector2::from(acknowledged.position);
+        if predicted.position.distance_to(server_position) <= RECONCILIATION_THRESHOLD {
+            return;
+        }
+
+        self.base_mut().set_position(server_position);
+        self.base_mut().set_velocity(predicted.velocity);
+
+        let mut input_history = std::mem::take(&mut self.input_history);
+        for input in input_history.pending_mut() {
+            self.simulate(input.direction, input.jump_pressed, input.delta);
+
+            input.position = self.base().get_position();
+            input.velocity = self.base().get_velocity();
+        }
+        self.input_history = input_history;
+    }
+
     fn handle_local_input(&mut self, delta: f64) {
         let input = Input::singleton();
-        let mut velocity = self.base().get_velocity();
         let is_on_floor = self.base().is_on_floor();
 
         let jump_pressed = input.is_action_just_pressed("jump");
         let direction = input.get_axis("move_left", "move_right");
 
+        self.simulate(direction, jump_pressed, delta);
+
+        // Handle animations using basic player
+        self.basic_player.handle_animation(direction, is_on_floor);
+
+        self.upload_stats.advance(delta);
+
+        let state = self.current_state(direction, jump_pressed, is_on_floor);
+        let mut sequence = None;
+        if self.send_policy.should_send(delta, &state) {
+            sequence = self.send_inputs(state.clone());
+
+            if sequence.is_none() {
+                // Nothing to reconcile against until the server hears from us again.
+                self.input_history.clear();
+                return;
+            }
+
+            self.send_policy.mark_sent(state);
+            self.upload_stats.record();
+        }
+
+        self.input_history.push(PredictedInput {
+            sequence,
+            direction,
+            jump_pressed,
+            delta,
+            position: self.base().get_position(),
+            velocity: self.base().get_velocity(),
+        });
+    }
+
+    /// Moves the body by one physics frame of input. Used for live input and
+    /// for replaying inputs after a correction.
+    fn simulate(&mut self, direction: f32, jump_pressed: bool, delta: f64) {
+        let mut velocity = self.base().get_velocity();
+        let is_on_floor = self.base().is_on_floor();
+
         // Apply gravity
         if !is_on_floor {
             velocity.y += self.base().get_gravity().y * delta as f32;
@@ -109,31 +292,61 @@ impl LocalPlayerNode {
         // Update velocity and move
         self.base_mut().set_velocity(velocity);
         self.base_mut().move_and_slide();
-
-        // Handle animations using basic player
-        self.basic_player.handle_animation(direction, is_on_floor);
-
-        self.send_inputs(direction, jump_pressed, is_on_floor);
     }
 
-    fn send_inputs(&self, direction: f32, jump_pressed: bool, is_on_floor: bool) {
+    fn current_state(
+        &self,
+        direction: f32,
+        jump_pressed: bool,
+        is_on_floor: bool,
+    ) -> DbPlayerState {
         let updated_velocity = self.base().get_velocity();
         let is_jumping = jump_pressed || (!is_on_floor && updated_velocity.y < 0.0);
 
-        let state = DbPlayerState {
+        DbPlayerState {
             position: DbVector2::from(self.base().get_position()),
             direction: direction as i32,
             is_jumping,
-        };
+            // Assigned by `send_inputs` on the connection.
+            input_sequence: 0,
+        }
+    }
+
+    /// Returns the sequence the state was sent under, if it was sent.
+    fn send_inputs(&self, state: DbPlayerState) -> Option<u32> {
+        // The server has already moved us to the portal's target spawn.
+        if SpacetimeDBManager::is_scene_transition_pending() {
+            return None;
+        }
 
         let Some(connection) = SpacetimeDBManager::get_read_connection() else {
             godot_print!("Could not get database connection!");
-            return;
+            return None;
         };
 
+        // Inputs are dropped until the connection is back; the server keeps the last state.
+        if connection.is_reconnecting() {
+            return None;
+        }
+
         match connection.send_inputs(state) {
-            Ok(_) => {}
-            Err(e) => godot_print!("Failed to send inputs: {}", e),
+            Ok(sequence) => Some(sequence),
+            Err(e) => {
+                godot_print!("Failed to send inputs: {}", e);
+                None
+            }
         }
     }
+
+    /// State uploads over the last second, for tuning the send policy.
+    #[func]
+    pub fn get_send_rate(&self) -> f64 {
+        self.upload_stats.sends_per_second()
+    }
+
+    /// Bytes of state uploaded over the last second, for tuning the send policy.
+    #[func]
+    pub fn get_upload_bytes_per_second(&self) -> f64 {
+        self.upload_stats.bytes_per_second()
+    }
 }
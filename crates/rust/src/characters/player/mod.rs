@@ -1,7 +1,15 @@
 mod basic_player;
+mod input_history;
 mod local_player;
+mod player_death;
 mod remote_player;
+mod send_policy;
+mod snapshot_buffer;
 
 pub use basic_player::*;
+pub use input_history::*;
 pub use local_player::*;
+pub use player_death::*;
 pub use remote_player::*;
+pub use send_policy::*;
+pub use snapshot_buffer::*;
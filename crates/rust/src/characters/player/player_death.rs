@@ -0,0 +1,41 @@
+use crate::{DbConnection, DbVector2, PlayerTableAccess};
+
+use spacetimedb_sdk::{Identity, TableWithPrimaryKey};
+
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /// Deaths waiting to be played by the `MultiplayerManager`.
+    static ref PLAYER_DEATHS: Mutex<Vec<PlayerDeath>> = Mutex::new(Vec::new());
+}
+
+/// A player row whose death counter went up; the server has already moved it
+/// to `respawn_at`.
+#[derive(Clone, Debug)]
+pub struct PlayerDeath {
+    pub identity: Identity,
+    pub died_at: DbVector2,
+    pub respawn_at: DbVector2,
+}
+
+impl PlayerDeath {
+    pub fn setup_multiplayer(connection: &DbConnection) {
+        connection.db.player().on_update(|_ctx, old, new| {
+            if new.deaths <= old.deaths {
+                return;
+            }
+
+            PLAYER_DEATHS.lock().unwrap().push(PlayerDeath {
+                identity: new.identity,
+                died_at: old.state.position.clone(),
+                respawn_at: new.state.position.clone(),
+            });
+        });
+    }
+
+    pub fn take_events() -> Vec<PlayerDeath> {
+        std::mem::take(&mut *PLAYER_DEATHS.lock().unwrap())
+    }
+}
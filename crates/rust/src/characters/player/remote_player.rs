@@ -1,24 +1,30 @@
-use crate::{DbPlayer, MultiplayerManager, RustLibError};
+use crate::{ClockSync, DbPlayer, MultiplayerManager, RustLibError};
 
-use super::BasicPlayer;
+use super::{BasicPlayer, Snapshot, SnapshotBuffer};
 
-use godot::classes::{AnimatedSprite2D, CharacterBody2D, ICharacterBody2D, Label, ResourceLoader};
+use game_rules::correction::{
+    Correction, MAX_CORRECTION_DISTANCE, POSITION_CORRECTION_STRENGTH,
+    VERTICAL_DIFF_FRAME_THRESHOLD, VERTICAL_DIFF_THRESHOLD, correction_for,
+};
+use godot::classes::{
+    AnimatedSprite2D, CharacterBody2D, CollisionShape2D, ICharacterBody2D, Label, ResourceLoader,
+};
 use godot::obj::BaseMut;
 use godot::prelude::*;
 
-/// Reduced strength to prevent jittering
-const POSITION_CORRECTION_STRENGTH: f32 = 0.05;
-/// Max distance before we snap instead of interpolate
-const MAX_CORRECTION_DISTANCE: f32 = 100.0;
-/// Don't correct small differences to reduce jittering
-const CORRECTION_DEADBAND: f32 = 1.0;
-
-/// Frames before vertical correction teleport
-const VERTICAL_DIFF_FRAME_THRESHOLD: i32 = 10;
-/// Frames before deadband teleport
-const DEADBAND_FRAME_THRESHOLD: i32 = 60;
-/// Minimum vertical difference to trigger correction
-const VERTICAL_DIFF_THRESHOLD: f32 = 3.0;
+/// Opacity of a player that lost its connection but may still come back
+const LINKDEAD_ALPHA: f32 = 0.4;
+
+/// How a remote player follows the states the server has for it.
+#[derive(GodotConvert, Var, Export, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[godot(via = i64)]
+pub enum RemoteSmoothing {
+    /// Simulates the latest direction and jump, nudged towards the server position.
+    #[default]
+    Heuristic = 0,
+    /// Replays buffered server states `interpolation_delay_ms` behind.
+    Interpolation = 1,
+}
 
 pub const PLAYER_SCENE_PATH: &str = "res://scenes/characters/remote_player.tscn";
 
@@ -34,6 +40,16 @@ struct RemoteState {
 pub struct RemotePlayerNode {
     basic_player: BasicPlayer,
 
+    #[export]
+    smoothing: RemoteSmoothing,
+    /// How far behind the server clock states are rendered in `Interpolation` mode.
+    #[export]
+    interpolation_delay_ms: f64,
+    /// How long a late player keeps moving along its last known path.
+    #[export]
+    max_extrapolation_ms: f64,
+    snapshots: SnapshotBuffer,
+
     /// Server state
     last_server_state: Option<RemoteState>,
 
@@ -49,6 +65,9 @@ pub struct RemotePlayerNode {
     /// Count frames for deadband condition
     deadband_frame_count: i32,
 
+    /// Frozen and ghosted while the server holds the player as linkdead
+    linkdead: bool,
+
     #[base]
     base: Base<CharacterBody2D>,
 }
@@ -58,12 +77,17 @@ impl ICharacterBody2D for RemotePlayerNode {
     fn init(base: Base<CharacterBody2D>) -> Self {
         Self {
             basic_player: BasicPlayer::new(),
+            smoothing: RemoteSmoothing::default(),
+            interpolation_delay_ms: 100.0,
+            max_extrapolation_ms: 100.0,
+            snapshots: SnapshotBuffer::default(),
             last_server_state: None,
             current_direction: 0,
             current_jumping: false,
             was_jumping: false,
             vertical_diff_frame_count: 0,
             deadband_frame_count: 0,
+            linkdead: false,
             base,
         }
     }
@@ -78,6 +102,20 @@ impl ICharacterBody2D for RemotePlayerNode {
     }
 
     fn physics_process(&mut self, delta: f64) {
+        if self.linkdead {
+            return;
+        }
+
+        if self.basic_player.is_dying() {
+            self.process_death(delta);
+            return;
+        }
+
+        if self.smoothing == RemoteSmoothing::Interpolation {
+            self.interpolate();
+            return;
+        }
+
         let mut velocity = self.base().get_velocity();
         let is_on_floor = self.base().is_on_floor();
 
@@ -176,8 +214,22 @@ impl RemotePlayerNode {
         self.base().get_global_position()
     }
 
+    /// `updated_at` is the server time the state was stored at, in microseconds.
     #[func]
-    pub fn set_player_position(&mut self, direction: i32, is_jumping: bool, position: Vector2) {
+    pub fn set_player_position(
+        &mut self,
+        direction: i32,
+        is_jumping: bool,
+        position: Vector2,
+        updated_at: i64,
+    ) {
+        self.snapshots.push(Snapshot {
+            server_micros: updated_at,
+            position,
+            direction,
+            is_jumping,
+        });
+
         self.last_server_state = Some(RemoteState {
             position,
             direction,
@@ -188,6 +240,92 @@ impl RemotePlayerNode {
         self.current_jumping = is_jumping;
     }
 
+    /// Places the player where the buffered states put it
+    /// `interpolation_delay_ms` ago on the server clock.
+    fn interpolate(&mut self) {
+        let render_micros =
+            ClockSync::server_now_micros() - (self.interpolation_delay_ms * 1000.0) as i64;
+        let max_extrapolation_micros = (self.max_extrapolation_ms * 1000.0) as i64;
+
+        let Some(snapshot) = self
+            .snapshots
+            .sample(render_micros, max_extrapolation_micros)
+        else {
+            return;
+        };
+
+        self.base_mut().set_global_position(snapshot.position);
+
+        // Only a jump is known to be airborne; falls animate as running.
+        self.basic_player
+            .handle_animation(snapshot.direction as f32, !snapshot.is_jumping);
+    }
+
+    /// Plays the death hop where the player died instead of snapping to the respawn.
+    pub fn play_death(&mut self, died_at: Vector2) {
+        if self.basic_player.is_dying() {
+            return;
+        }
+
+        self.base_mut().set_global_position(died_at);
+
+        let mut velocity = self.base().get_velocity();
+        self.basic_player.start_death(&mut velocity);
+        self.base_mut().set_velocity(velocity);
+
+        self.set_collision_disabled(true);
+    }
+
+    fn process_death(&mut self, delta: f64) {
+        if self.basic_player.process_death(delta) {
+            // Server states kept arriving meanwhile; resume from the latest one.
+            if let Some(server_state) = &self.last_server_state {
+                let position = server_state.position;
+                self.base_mut().set_global_position(position);
+            }
+            self.base_mut().set_velocity(Vector2::ZERO);
+            self.set_collision_disabled(false);
+
+            return;
+        }
+
+        let mut velocity = self.base().get_velocity();
+        velocity.y += self.base().get_gravity().y * delta as f32;
+        self.base_mut().set_velocity(velocity);
+        self.base_mut().move_and_slide();
+    }
+
+    fn set_collision_disabled(&self, disabled: bool) {
+        if let Some(mut collision_shape) = self
+            .base()
+            .try_get_node_as::<CollisionShape2D>("CollisionShape2D")
+        {
+            collision_shape.set_deferred("disabled", &disabled.to_variant());
+        }
+    }
+
+    pub fn set_linkdead(&mut self, linkdead: bool) {
+        if self.linkdead == linkdead {
+            return;
+        }
+
+        self.linkdead = linkdead;
+        // States from before the disconnect are not worth interpolating from.
+        self.snapshots.clear();
+
+        let alpha = if linkdead { LINKDEAD_ALPHA } else { 1.0 };
+        self.base_mut()
+            .set_modulate(Color::from_rgba(1.0, 1.0, 1.0, alpha));
+
+        if let Some(animated_sprite) = &mut self.basic_player.animated_sprite {
+            if linkdead {
+                animated_sprite.pause();
+            } else {
+                animated_sprite.play();
+            }
+        }
+    }
+
     fn apply_position_correction(&mut self, server_state: &RemoteState, _delta: f64) {
         let current_pos = self.base().get_global_position();
         let server_pos = server_state.position;
@@ -223,21 +361,20 @@ impl RemotePlayerNode {
             self.vertical_diff_frame_count = 0;
         }
 
-        if distance <= CORRECTION_DEADBAND {
-            self.deadband_frame_count = 0;
-            return;
-        }
-
-        if self.deadband_frame_count > DEADBAND_FRAME_THRESHOLD {
-            self.base_mut().set_global_position(server_pos);
+        match correction_for(distance, self.deadband_frame_count) {
+            Correction::None => {
+                self.deadband_frame_count = 0;
+            }
+            Correction::Snap => {
+                self.base_mut().set_global_position(server_pos);
+            }
+            Correction::Interpolate => {
+                let correction_vector = (server_pos - current_pos) * POSITION_CORRECTION_STRENGTH;
+                let corrected_pos = current_pos + correction_vector;
+                self.base_mut().set_global_position(corrected_pos);
 
-            return;
+                self.deadband_frame_count += 1;
+            }
         }
-
-        let correction_vector = (server_pos - current_pos) * POSITION_CORRECTION_STRENGTH;
-        let corrected_pos = current_pos + correction_vector;
-        self.base_mut().set_global_position(corrected_pos);
-
-        self.deadband_frame_count += 1;
     }
 }
@@ -0,0 +1,107 @@
+use std::collections::VecDeque;
+
+use crate::DbPlayerState;
+
+use godot::prelude::*;
+
+/// BSATN size of a `DbPlayerState` argument: two f32 for the position, the
+/// direction, the jump flag and the input sequence. Message framing is not counted.
+pub const STATE_UPLOAD_BYTES: usize = 17;
+
+/// Decides which physics frames upload the local player's state.
+#[derive(Debug)]
+pub struct SendPolicy {
+    /// Most uploads per second for position changes alone.
+    pub max_rate: f64,
+    /// Longest the player goes without an upload, even when standing still.
+    pub heartbeat_interval: f64,
+    /// Distance from the last uploaded position that is worth an upload.
+    pub position_threshold: f32,
+
+    last_sent: Option<DbPlayerState>,
+    since_last_send: f64,
+}
+
+impl Default for SendPolicy {
+    fn default() -> Self {
+        Self::new(20.0, 1.0, 2.0)
+    }
+}
+
+impl SendPolicy {
+    pub fn new(max_rate: f64, heartbeat_interval: f64, position_threshold: f32) -> Self {
+        Self {
+            max_rate,
+            heartbeat_interval,
+            position_threshold,
+            last_sent: None,
+            since_last_send: 0.0,
+        }
+    }
+
+    /// Direction and jump changes go out immediately, position changes at
+    /// most `max_rate` times a second.
+    pub fn should_send(&mut self, delta: f64, state: &DbPlayerState) -> bool {
+        self.since_last_send += delta;
+
+        let Some(last_sent) = &self.last_sent else {
+            return true;
+        };
+
+        if last_sent.direction != state.direction || last_sent.is_jumping != state.is_jumping {
+            return true;
+        }
+
+        if self.since_last_send >= self.heartbeat_interval {
+            return true;
+        }
+
+        let moved = Vector2::from(last_sent.position.clone())
+            .distance_to(Vector2::from(state.position.clone()));
+
+        self.since_last_send * self.max_rate >= 1.0 && moved > self.position_threshold
+    }
+
+    pub fn mark_sent(&mut self, state: DbPlayerState) {
+        self.last_sent = Some(state);
+        self.since_last_send = 0.0;
+    }
+
+    /// Sends the next state regardless, e.g. after a respawn.
+    pub fn reset(&mut self) {
+        self.last_sent = None;
+    }
+}
+
+/// Uploads within the last second, for tuning the send policy.
+#[derive(Default, Debug)]
+pub struct UploadStats {
+    clock: f64,
+    sent_at: VecDeque<f64>,
+}
+
+impl UploadStats {
+    pub fn advance(&mut self, delta: f64) {
+        self.clock += delta;
+
+        while self
+            .sent_at
+            .front()
+            .is_some_and(|&sent_at| sent_at <= self.clock - 1.0)
+        {
+            self.sent_at.pop_front();
+        }
+    }
+
+    pub fn record(&mut self) {
+        self.sent_at.push_back(self.clock);
+    }
+
+    pub fn sends_per_second(&self) -> f64 {
+        self.sent_at.len() as f64
+    }
+
+    pub fn bytes_per_second(&self) -> f64 {
+        (self.sent_at.len() * STATE_UPLOAD_BYTES) as f64
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::VecDeque;
+
+use game_rules::correction::MAX_CORRECTION_DISTANCE;
+use godot::prelude::*;
+
+/// Snapshots kept at most, should rendering stop sampling for a while.
+const MAX_SNAPSHOTS: usize = 64;
+
+/// A remote player's state as the server stored it at `server_micros`.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    pub server_micros: i64,
+    pub position: Vector2,
+    pub direction: i32,
+    pub is_jumping: bool,
+}
+
+/// Server states of one remote player, rendered a little in the past so
+/// there is usually a newer state to interpolate towards.
+#[derive(Default, Debug)]
+pub struct SnapshotBuffer {
+    snapshots: VecDeque<Snapshot>,
+}
+
+impl SnapshotBuffer {
+    /// Adds `snapshot` unless it is not newer than the latest one; the same
+    /// row is reported every frame until the player sends again.
+    pub fn push(&mut self, snapshot: Snapshot) {
+        if let Some(latest) = self.snapshots.back()
+            && latest.server_micros >= snapshot.server_micros
+        {
+            return;
+        }
+
+        if self.snapshots.len() == MAX_SNAPSHOTS {
+            self.snapshots.pop_front();
+        }
+
+        self.snapshots.push_back(snapshot);
+    }
+
+    /// State at `render_micros` server time. Between two snapshots the
+    /// position is interpolated; past the latest one it is extrapolated from
+    /// the last two for at most `max_extrapolation_micros`, then held.
+    pub fn sample(
+        &mut self,
+        render_micros: i64,
+        max_extrapolation_micros: i64,
+    ) -> Option<Snapshot> {
+        // Keep the snapshot right before the render time and everything after it.
+        while self.snapshots.len() > 2 && self.snapshots[1].server_micros <= render_micros {
+            self.snapshots.pop_front();
+        }
+
+        let from = self.snapshots.front()?;
+        let Some(to) = self.snapshots.get(1) else {
+            return Some(from.clone());
+        };
+
+        if render_micros <= from.server_micros {
+            return Some(from.clone());
+        }
+
+        let latest = if render_micros < to.server_micros {
+            from
+        } else {
+            to
+        };
+
+        // Teleports, e.g. a respawn, are not worth sliding across the level for.
+        if from.position.distance_to(to.position) > MAX_CORRECTION_DISTANCE {
+            return Some(latest.clone());
+        }
+
+        let span = to.server_micros - from.server_micros;
+        let elapsed = (render_micros - from.server_micros).min(span + max_extrapolation_micros);
+        let weight = elapsed as f32 / span as f32;
+
+        Some(Snapshot {
+            server_micros: render_micros,
+            position: from.position + (to.position - from.position) * weight,
+            ..latest.clone()
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.snapshots.clear();
+    }
+}
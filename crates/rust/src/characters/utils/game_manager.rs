@@ -1,22 +1,16 @@
-use crate::{DbConnection, DbVector2};
+use crate::{DbConnection, ReconnectStatus, SpacetimeDBManager};
 
 use godot::classes::{INode, Label, Node};
 use godot::prelude::*;
 
 use spacetimedb_sdk::DbContext;
 
-use std::sync::RwLock;
-
-use lazy_static::lazy_static;
-
-lazy_static! {
-    pub static ref COIN_QUEUE: RwLock<Vec<DbVector2>> = RwLock::new(Vec::new());
-}
-
 #[derive(GodotClass)]
 #[class(base=Node)]
 pub struct GameManager {
     score_label: Option<Gd<Label>>,
+    status_label: Option<Gd<Label>>,
+    health_label: Option<Gd<Label>>,
 
     #[base]
     base: Base<Node>,
@@ -27,15 +21,22 @@ impl INode for GameManager {
     fn init(base: Base<Node>) -> Self {
         Self {
             score_label: None,
+            status_label: None,
+            health_label: None,
             base,
         }
     }
 
     fn ready(&mut self) {
         self.score_label = self.base().try_get_node_as::<Label>("ScoreLabel");
+        self.status_label = self.base().try_get_node_as::<Label>("StatusLabel");
+        self.health_label = self.base().try_get_node_as::<Label>("HealthLabel");
     }
 
-    fn process(&mut self, _delta: f64) {}
+    fn process(&mut self, _delta: f64) {
+        self.update_connection_status();
+        self.update_health();
+    }
 }
 
 #[godot_api]
@@ -45,4 +46,47 @@ impl GameManager {
             .subscription_builder()
             .subscribe(["SELECT * FROM world_scene", "SELECT * FROM player_score"]);
     }
+
+    fn update_connection_status(&mut self) {
+        let Some(label) = &mut self.status_label else {
+            return;
+        };
+
+        let status = match SpacetimeDBManager::get_read_connection() {
+            Some(db_manager) => db_manager.get_reconnect_status(),
+            None => return,
+        };
+
+        let text = match status {
+            ReconnectStatus::Idle => String::new(),
+            ReconnectStatus::Waiting { attempt, .. } => {
+                format!("Connection lost, reconnecting (attempt {attempt})...")
+            }
+            ReconnectStatus::Failed => "Connection lost".to_string(),
+        };
+
+        if label.get_text().to_string() != text {
+            label.set_text(&text);
+        }
+    }
+
+    fn update_health(&mut self) {
+        let Some(label) = &mut self.health_label else {
+            return;
+        };
+
+        let player = match SpacetimeDBManager::get_read_connection() {
+            Some(db_manager) => db_manager.get_local_player(),
+            None => return,
+        };
+
+        let text = match player {
+            Ok(Some(player)) => format!("Health: {}  Deaths: {}", player.health, player.deaths),
+            _ => String::new(),
+        };
+
+        if label.get_text().to_string() != text {
+            label.set_text(&text);
+        }
+    }
 }
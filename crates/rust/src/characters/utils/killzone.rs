@@ -1,14 +1,17 @@
-use crate::SpacetimeDBManager;
+use crate::{GreenSlimeNode, Hazard, LocalPlayerNode, SpacetimeDBManager};
 
-use godot::classes::{Area2D, CollisionShape2D, IArea2D, Timer};
+use godot::classes::{Area2D, IArea2D, Timer};
 use godot::prelude::*;
 
+/// Reports the local player touching it to the server, which applies the
+/// damage. Remote players are hurt by their own clients.
 #[derive(GodotClass)]
 #[class(base=Area2D)]
 pub struct KillZoneArea {
+    /// Re-reports while the player stays inside, once its invulnerability may have run out.
     timer: Option<Gd<Timer>>,
 
-    entered_body: Option<Gd<Node2D>>,
+    entered_body: Option<Gd<LocalPlayerNode>>,
 
     #[base]
     base: Base<Area2D>,
@@ -37,7 +40,8 @@ impl IArea2D for KillZoneArea {
 #[godot_api]
 impl KillZoneArea {
     fn connect_signals(&mut self) {
-        self.connect_body_entered_callback();
+        let callback = self.base().callable("on_body_entered");
+        self.base_mut().connect("body_entered", &callback);
 
         let callback = self.base().callable("on_timer_timeout");
         if let Some(timer) = &mut self.timer {
@@ -47,72 +51,53 @@ impl KillZoneArea {
 
     #[func]
     fn on_body_entered(&mut self, body: Gd<Node2D>) {
-        self.delete_body_entered_callback();
-
-        Self::set_deferred_collision_shape(&body, true);
-
-        self.entered_body = Some(body);
+        let Ok(player) = body.try_cast::<LocalPlayerNode>() else {
+            return;
+        };
 
-        self.start_timer();
+        self.entered_body = Some(player);
+        self.report_hazard();
     }
 
     #[func]
     fn on_timer_timeout(&mut self) {
-        let Some(entered_body) = &mut self.entered_body else {
-            self.connect_body_entered_callback();
-
+        let Some(entered_body) = &self.entered_body else {
             return;
         };
 
-        Self::set_deferred_collision_shape(entered_body, false);
-
-        let Some(connection) = SpacetimeDBManager::get_read_connection() else {
-            godot_error!("Could not get database connection!");
+        if !entered_body.is_instance_valid() || !self.base().overlaps_body(entered_body) {
+            self.entered_body = None;
             return;
-        };
-
-        match connection.get_spawn_point() {
-            Ok(Some(spawn_point)) => {
-                entered_body.set_position(spawn_point);
-            }
-            Ok(None) => {
-                godot_error!("Could not get spawn_point!");
-            }
-            Err(e) => {
-                godot_error!("Could not get spawn_point: {:?}", e);
-            }
         }
 
-        self.entered_body = None;
+        self.report_hazard();
+    }
 
-        self.start_timer();
+    /// Killzones on an enemy hurt like that enemy, all others are fatal falls.
+    fn hazard(&self) -> Hazard {
+        self.base()
+            .get_parent()
+            .and_then(|parent| parent.try_cast::<GreenSlimeNode>().ok())
+            .map_or(Hazard::KillZone, |enemy| {
+                Hazard::Enemy(enemy.bind().get_enemy_id())
+            })
     }
 
-    fn set_deferred_collision_shape(body: &Gd<Node2D>, state: bool) {
-        if let Some(mut collision_shape) =
-            body.try_get_node_as::<CollisionShape2D>("CollisionShape2D")
-        {
-            collision_shape.set_deferred("disabled", &state.to_variant());
+    fn report_hazard(&mut self) {
+        let hazard = self.hazard();
+
+        if let Some(connection) = SpacetimeDBManager::get_read_connection() {
+            if let Err(e) = connection.report_hazard(hazard.clone()) {
+                godot_error!("Failed to report hazard {:?}: {}", hazard, e);
+            }
         } else {
-            godot_error!("Could not find CollisionShape2D on entered body");
+            godot_error!("Could not get database connection!");
         }
-    }
 
-    fn start_timer(&mut self) {
         if let Some(timer) = &mut self.timer {
             timer.start();
         } else {
             godot_error!("Timer not available to start");
         }
     }
-
-    fn connect_body_entered_callback(&mut self) {
-        let callback = self.base().callable("on_body_entered");
-        self.base_mut().connect("body_entered", &callback);
-    }
-
-    fn delete_body_entered_callback(&mut self) {
-        let callable = self.base().callable("on_body_entered");
-        self.base_mut().disconnect("body_entered", &callable);
-    }
 }
@@ -1,3 +1,4 @@
+use godot::builtin::Color;
 use godot::classes::AnimatedSprite2D;
 use godot::meta::ToGodot;
 
@@ -24,3 +25,14 @@ pub fn handle_player_animation(
         animated_sprite.call("play", &["jump".to_variant()]);
     }
 }
+
+/// Tips the sprite over and fades it out as `progress` goes from 0 to 1.
+pub fn handle_death_animation(animated_sprite: &mut AnimatedSprite2D, progress: f32) {
+    animated_sprite.set_rotation(progress * std::f32::consts::PI);
+    animated_sprite.set_modulate(Color::from_rgba(1.0, 1.0, 1.0, 1.0 - progress));
+}
+
+pub fn reset_death_animation(animated_sprite: &mut AnimatedSprite2D) {
+    animated_sprite.set_rotation(0.0);
+    animated_sprite.set_modulate(Color::WHITE);
+}
@@ -1,14 +1,13 @@
 use crate::{
-    CoinNode, CoinTableAccess, DbConnection, GreenSlimeNode, LocalPlayerNode, MultiplayerManager,
-    PlatformNode, RustLibError, WorldSceneTableAccess, get_diff_between_timestamps,
-    get_world_scene, update_timestamp,
+    CheckpointArea, CheckpointTableAccess, ChestNode, ChestTableAccess, DbConnection,
+    LocalPlayerNode, MultiplayerManager, PlatformNode, PlatformTableAccess, PlayerTableAccess,
+    RustLibError, WorldSceneTableAccess, get_diff_between_timestamps, get_world_clock,
 };
 
 use godot::classes::{PackedScene, ResourceLoader};
 use godot::{obj::BaseMut, prelude::*};
 use spacetimedb_sdk::{DbContext, Table};
 
-const COIN_SCENE_PATH: &str = "res://scenes/entities/coin.tscn";
 const LOCAL_PLAYER_SCENE_PATH: &str = "res://scenes/characters/local_player.tscn";
 
 pub struct WorldBootstrap {}
@@ -27,7 +26,7 @@ impl WorldBootstrap {
     pub fn setup_multiplayer(connection: &DbConnection) {
         connection
             .subscription_builder()
-            .subscribe("SELECT * FROM world_scene");
+            .subscribe(["SELECT * FROM world_scene", "SELECT * FROM world_clock"]);
     }
 
     pub fn boot_player(
@@ -35,12 +34,14 @@ impl WorldBootstrap {
         multiplayer_base: &mut BaseMut<MultiplayerManager>,
         connection: &DbConnection,
         player_name: &str,
+        scene_id: u32,
     ) -> Result<(), RustLibError> {
-        self.bootstrap_coins(multiplayer_base, connection)?;
-        self.bootstrap_player(multiplayer_base, connection, player_name)?;
+        self.bootstrap_player(multiplayer_base, connection, player_name, scene_id)?;
 
-        self.sync_platforms(multiplayer_base, connection)?;
-        self.sync_animated_enemies(multiplayer_base, connection)?;
+        self.sync_platforms(multiplayer_base, connection, scene_id)?;
+        self.sync_checkpoints(multiplayer_base, connection, scene_id);
+        self.sync_chests(multiplayer_base, connection, scene_id);
+        self.clear_enemy_placeholders(multiplayer_base);
 
         Ok(())
     }
@@ -50,19 +51,25 @@ impl WorldBootstrap {
         multiplayer_base: &mut BaseMut<MultiplayerManager>,
         connection: &DbConnection,
         _player_name: &str,
+        scene_id: u32,
     ) -> Result<(), RustLibError> {
         let player_id = connection.identity();
 
-        // TODO: make it flexible
-        let spawn_position = connection
-            .db
-            .world_scene()
-            .iter()
-            .find(|x| x.scene_id == 1)
-            .ok_or(RustLibError::WorldSetup(
-                "No spawn position found".to_string(),
-            ))?
-            .spawn_point;
+        // A returning player resumes where its row says it was left.
+        let spawn_position = match connection.db.player().identity().find(&player_id) {
+            Some(player) if player.scene_id == scene_id => player.state.position,
+            _ => {
+                connection
+                    .db
+                    .world_scene()
+                    .scene_id()
+                    .find(&scene_id)
+                    .ok_or(RustLibError::WorldSetup(
+                        "No spawn position found".to_string(),
+                    ))?
+                    .spawn_point
+            }
+        };
 
         let mut resource_loader = ResourceLoader::singleton();
         let Some(packed_scene) = resource_loader.load(LOCAL_PLAYER_SCENE_PATH) else {
@@ -99,123 +106,99 @@ impl WorldBootstrap {
         Ok(())
     }
 
-    fn bootstrap_coins(
+    fn sync_platforms(
         &self,
         multiplayer_base: &mut BaseMut<MultiplayerManager>,
         connection: &DbConnection,
+        scene_id: u32,
     ) -> Result<(), RustLibError> {
-        let coins = connection
-            .db
-            .coin()
-            .iter()
-            .filter(|x| x.scene_id == 1 && x.collected_by.is_none())
-            .collect::<Vec<_>>();
-
-        for coin in coins {
-            let mut resource_loader = ResourceLoader::singleton();
-            let Some(packed_scene) = resource_loader.load(COIN_SCENE_PATH) else {
-                godot_print!("Failed to load resource at {}", COIN_SCENE_PATH);
-                continue;
-            };
+        let world_clock = get_world_clock(connection, scene_id)?;
+        let t_micro = get_diff_between_timestamps(&world_clock) as f64;
 
-            let Ok(scene) = packed_scene.try_cast::<PackedScene>() else {
-                godot_print!("Failed to cast resource to PackedScene");
-                continue;
-            };
+        let Some(platform_group) = multiplayer_base.try_get_node_as::<Node>("Platforms") else {
+            return Ok(());
+        };
 
-            let Some(instance) = scene.instantiate() else {
-                godot_print!("Failed to instantiate scene");
+        for row in connection.db.platform().iter() {
+            if row.scene_id != scene_id {
                 continue;
-            };
+            }
 
-            let Ok(mut coin_node) = instance.try_cast::<CoinNode>() else {
-                godot_print!("Failed to cast instance to Coin");
+            let Some(mut platform_node) =
+                platform_group.try_get_node_as::<PlatformNode>(row.name.as_str())
+            else {
+                godot_warn!("Platform {} has no node in the scene", row.name);
                 continue;
             };
 
-            coin_node.set_position(coin.position.into());
-            let coin_id_sn = StringName::from(coin.coin_id.to_string().as_str());
-            coin_node.set_name(&coin_id_sn);
-
-            multiplayer_base.add_child(&coin_node);
+            let mut platform = platform_node.bind_mut();
+            platform.attach_platform(&row);
+            platform.sync_based_on_time(t_micro);
         }
 
         Ok(())
     }
 
-    fn sync_platforms(
+    fn sync_checkpoints(
         &self,
         multiplayer_base: &mut BaseMut<MultiplayerManager>,
         connection: &DbConnection,
-    ) -> Result<(), RustLibError> {
-        if let Err(e) = connection.reducers.update_timestamp() {
-            godot_print!("Failed to update timestamp: {}", e);
-        }
-
-        let platform_group = multiplayer_base
-            .try_get_node_as::<Node>("Platforms")
-            .unwrap();
-        let platform_count = platform_group.get_child_count();
-
-        for i in 1..=platform_count {
-            let platform =
-                platform_group.try_get_node_as::<PlatformNode>(format!("Platform{}", i).as_str());
+        scene_id: u32,
+    ) {
+        let Some(checkpoint_group) = multiplayer_base.try_get_node_as::<Node>("Checkpoints") else {
+            return;
+        };
 
-            match connection.reducers.update_timestamp() {
-                Ok(_) => {}
-                Err(e) => godot_print!("Failed to update timestamp: {}", e),
+        for row in connection.db.checkpoint().iter() {
+            if row.scene_id != scene_id {
+                continue;
             }
 
-            match connection.frame_tick() {
-                Ok(_) => {
-                    let world_scene = get_world_scene(connection)?;
-                    let t_micro = get_diff_between_timestamps(&world_scene) as f64;
+            let Some(mut checkpoint_node) =
+                checkpoint_group.try_get_node_as::<CheckpointArea>(row.name.as_str())
+            else {
+                godot_warn!("Checkpoint {} has no node in the scene", row.name);
+                continue;
+            };
 
-                    if let Some(mut platform) = platform {
-                        platform.bind_mut().sync_based_on_time(t_micro);
-                    }
-                }
-                Err(e) => godot_print!("Database tick error: {}", e),
-            }
+            checkpoint_node.bind_mut().attach_checkpoint(&row);
         }
-
-        Ok(())
     }
 
-    fn sync_animated_enemies(
+    fn sync_chests(
         &self,
         multiplayer_base: &mut BaseMut<MultiplayerManager>,
         connection: &DbConnection,
-    ) -> Result<(), RustLibError> {
-        if let Err(e) = connection.reducers.update_timestamp() {
-            godot_print!("Failed to update timestamp: {}", e);
-        }
-
-        let enemy_group = multiplayer_base.try_get_node_as::<Node>("Enemies").unwrap();
-        let enemy_count = enemy_group.get_child_count();
-
-        for i in 1..=enemy_count {
-            let enemy =
-                enemy_group.try_get_node_as::<GreenSlimeNode>(format!("Enemy{}", i).as_str());
+        scene_id: u32,
+    ) {
+        let Some(chest_group) = multiplayer_base.try_get_node_as::<Node>("Chests") else {
+            return;
+        };
 
-            match connection.reducers.update_timestamp() {
-                Ok(_) => {}
-                Err(e) => godot_print!("Failed to update timestamp: {}", e),
+        for row in connection.db.chest().iter() {
+            if row.scene_id != scene_id {
+                continue;
             }
 
-            match connection.frame_tick() {
-                Ok(_) => {
-                    let world_scene = get_world_scene(connection)?;
-                    let t_micro = get_diff_between_timestamps(&world_scene) as f64;
+            let Some(mut chest_node) = chest_group.try_get_node_as::<ChestNode>(row.name.as_str())
+            else {
+                godot_warn!("Chest {} has no node in the scene", row.name);
+                continue;
+            };
 
-                    if let Some(mut enemy) = enemy {
-                        enemy.bind_mut().sync_based_on_time(t_micro);
-                    }
-                }
-                Err(e) => godot_print!("Database tick error: {}", e),
-            }
+            chest_node.bind_mut().attach_chest(&row);
         }
+    }
 
-        Ok(())
+    /// Enemies placed in the editor only feed `scene-export`; in game they are
+    /// spawned from the server `enemy` table by `MultiplayerManager`.
+    fn clear_enemy_placeholders(&self, multiplayer_base: &mut BaseMut<MultiplayerManager>) {
+        let Some(enemy_group) = multiplayer_base.try_get_node_as::<Node>("Enemies") else {
+            return;
+        };
+
+        for mut placeholder in enemy_group.get_children().iter_shared() {
+            placeholder.queue_free();
+        }
     }
 }
@@ -0,0 +1,126 @@
+use crate::{DbConnection, clock_probe};
+
+use godot::global::godot_print;
+
+use spacetimedb_sdk::{DbContext, Timestamp};
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use lazy_static::lazy_static;
+
+/// Probes sent back to back after connecting, before the estimate is trusted.
+const WARMUP_PROBES: u32 = 5;
+const WARMUP_INTERVAL: Duration = Duration::from_millis(200);
+const PROBE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Weight of a new sample in the smoothed offset and round trip.
+const SMOOTHING: f64 = 0.2;
+/// Samples whose round trip exceeds the smoothed one by this factor are dropped,
+/// their offset is dominated by queueing rather than clock skew.
+const OUTLIER_RTT_FACTOR: f64 = 2.0;
+
+lazy_static! {
+    static ref CLOCK_SYNC: Mutex<ClockSync> = Mutex::new(ClockSync::default());
+}
+
+/// NTP-style estimate of how far the server clock is ahead of the local one.
+#[derive(Default)]
+pub struct ClockSync {
+    offset_micros: Option<f64>,
+    rtt_micros: Option<f64>,
+    samples: u32,
+    last_probe_at: Option<Instant>,
+}
+
+impl ClockSync {
+    pub fn setup_multiplayer(connection: &DbConnection) {
+        *CLOCK_SYNC.lock().unwrap() = ClockSync::default();
+
+        connection.reducers.on_clock_probe(|ctx, client_time| {
+            if ctx.event.caller_identity != ctx.identity() {
+                return;
+            }
+
+            if let spacetimedb_sdk::Status::Committed = ctx.event.status {
+                let sent = client_time.to_micros_since_unix_epoch();
+                let server = ctx.event.timestamp.to_micros_since_unix_epoch();
+
+                CLOCK_SYNC
+                    .lock()
+                    .unwrap()
+                    .add_sample(sent, server, local_now_micros());
+            }
+        });
+    }
+
+    /// Sends a probe when one is due; called once per frame.
+    pub fn tick(connection: &DbConnection) {
+        let mut clock_sync = CLOCK_SYNC.lock().unwrap();
+
+        let interval = if clock_sync.samples < WARMUP_PROBES {
+            WARMUP_INTERVAL
+        } else {
+            PROBE_INTERVAL
+        };
+
+        if clock_sync
+            .last_probe_at
+            .is_some_and(|sent| sent.elapsed() < interval)
+        {
+            return;
+        }
+
+        clock_sync.last_probe_at = Some(Instant::now());
+
+        let now = Timestamp::from_micros_since_unix_epoch(local_now_micros());
+        if let Err(e) = connection.reducers.clock_probe(now) {
+            godot_print!("Failed to send clock probe: {}", e);
+        }
+    }
+
+    /// Current server time as estimated from the local clock and the smoothed offset.
+    pub fn server_now_micros() -> i64 {
+        local_now_micros() + Self::get_offset_micros().unwrap_or(0.0) as i64
+    }
+
+    pub fn get_offset_micros() -> Option<f64> {
+        CLOCK_SYNC.lock().unwrap().offset_micros
+    }
+
+    pub fn get_rtt_micros() -> Option<f64> {
+        CLOCK_SYNC.lock().unwrap().rtt_micros
+    }
+
+    /// `sent` and `received` are local times around the round trip, `server`
+    /// is when the reducer ran. The server time is assumed to sit halfway.
+    fn add_sample(&mut self, sent: i64, server: i64, received: i64) {
+        let rtt = (received - sent) as f64;
+        let offset = server as f64 - (sent + received) as f64 / 2.0;
+
+        if let Some(smoothed_rtt) = self.rtt_micros
+            && self.samples >= WARMUP_PROBES
+            && rtt > smoothed_rtt * OUTLIER_RTT_FACTOR
+        {
+            return;
+        }
+
+        self.samples += 1;
+        self.offset_micros = Some(smooth(self.offset_micros, offset));
+        self.rtt_micros = Some(smooth(self.rtt_micros, rtt));
+    }
+}
+
+fn smooth(current: Option<f64>, sample: f64) -> f64 {
+    match current {
+        Some(current) => current + SMOOTHING * (sample - current),
+        None => sample,
+    }
+}
+
+pub fn local_now_micros() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_micros() as i64
+}
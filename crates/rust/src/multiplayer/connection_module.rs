@@ -1,10 +1,11 @@
 use crate::{DbConnection, ErrorContext, RustLibError};
 
-use godot::global::godot_print;
+use godot::global::{godot_print, randf};
 
 use spacetimedb_sdk::{Error, credentials};
 
 use std::hash::{DefaultHasher, Hash, Hasher};
+use std::time::{Duration, Instant};
 
 const DB_NAME: &str = "kik-pok";
 
@@ -14,17 +15,41 @@ const DB_HOST: &str = "https://maincloud.spacetimedb.com";
 #[cfg(not(feature = "remote-db"))]
 const DB_HOST: &str = "127.0.0.1:3000";
 
+const RECONNECT_BASE_DELAY: Duration = Duration::from_millis(500);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+const RECONNECT_MAX_ATTEMPTS: u32 = 10;
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum ReconnectStatus {
+    #[default]
+    Idle,
+    /// Waiting for `retry_at` before making reconnect attempt `attempt`.
+    Waiting {
+        attempt: u32,
+        retry_at: Instant,
+    },
+    Failed,
+}
+
 #[derive(Default)]
 pub struct ConnectionModule {
     connection: Option<DbConnection>,
+    username: Option<String>,
+    reconnect_status: ReconnectStatus,
 }
 
 impl ConnectionModule {
     pub fn new() -> Self {
-        Self { connection: None }
+        Self {
+            connection: None,
+            username: None,
+            reconnect_status: ReconnectStatus::Idle,
+        }
     }
 
     pub fn connect(&mut self, username: &str) -> Result<(), RustLibError> {
+        self.username = Some(username.to_string());
+
         let jwt = Self::get_creds_store(username)
             .load()
             .map_err(|e| RustLibError::Credential { source: e })?;
@@ -46,6 +71,88 @@ impl ConnectionModule {
         }
     }
 
+    /// Drops the dead connection and schedules the first reconnect attempt.
+    pub fn begin_reconnect(&mut self) {
+        self.connection = None;
+        self.reconnect_status = ReconnectStatus::Waiting {
+            attempt: 1,
+            retry_at: Instant::now() + Self::backoff_delay(1),
+        };
+    }
+
+    /// Makes a reconnect attempt once its backoff has elapsed.
+    /// Returns `Ok(true)` when a new connection is established.
+    pub fn poll_reconnect(&mut self) -> Result<bool, RustLibError> {
+        let ReconnectStatus::Waiting { attempt, retry_at } = self.reconnect_status else {
+            return Ok(false);
+        };
+
+        if Instant::now() < retry_at {
+            return Ok(false);
+        }
+
+        let username = self
+            .username
+            .clone()
+            .ok_or(RustLibError::WrongConnectionState(
+                "Cannot reconnect without a username.".to_string(),
+            ))?;
+
+        // Only the stored token is used, a fresh identity would lose the player.
+        let result = Self::get_creds_store(&username)
+            .load()
+            .map_err(|e| RustLibError::Credential { source: e })
+            .and_then(|jwt| self.connect_to_db_with_creds(jwt, Self::get_creds_store(&username)));
+
+        match result {
+            Ok(connection) => {
+                godot_print!("Reconnected after {} attempt(s)", attempt);
+
+                self.connection = Some(connection);
+                self.reconnect_status = ReconnectStatus::Idle;
+
+                Ok(true)
+            }
+            Err(e) if attempt >= RECONNECT_MAX_ATTEMPTS => {
+                godot_print!("Giving up reconnecting after {} attempts: {}", attempt, e);
+
+                self.reconnect_status = ReconnectStatus::Failed;
+
+                Err(e)
+            }
+            Err(e) => {
+                let delay = Self::backoff_delay(attempt + 1);
+                godot_print!(
+                    "Reconnect attempt {} failed: {}, retrying in {:?}",
+                    attempt,
+                    e,
+                    delay
+                );
+
+                self.reconnect_status = ReconnectStatus::Waiting {
+                    attempt: attempt + 1,
+                    retry_at: Instant::now() + delay,
+                };
+
+                Ok(false)
+            }
+        }
+    }
+
+    pub fn get_reconnect_status(&self) -> &ReconnectStatus {
+        &self.reconnect_status
+    }
+
+    /// Exponential backoff with "equal jitter": half of the delay is fixed,
+    /// the other half random, so clients dropped together do not retry together.
+    fn backoff_delay(attempt: u32) -> Duration {
+        let exponential =
+            RECONNECT_BASE_DELAY.saturating_mul(1 << attempt.saturating_sub(1).min(16));
+        let capped = exponential.min(RECONNECT_MAX_DELAY);
+
+        capped / 2 + capped.mul_f64(randf() / 2.0)
+    }
+
     pub fn get_connection(&self) -> Result<&DbConnection, RustLibError> {
         self.connection
             .as_ref()
@@ -1,3 +1,18 @@
+use crate::RustLibError;
+
+use godot::classes::SceneTree;
+use godot::global::Error;
+use godot::prelude::*;
+
+/// Scene the login screen registers new players into.
+pub const ENTRY_SCENE_ID: u32 = 1;
+
+/// Maps server `world_scene` names to the Godot scenes that render them.
+const SCENE_PATHS: &[(&str, &str)] = &[
+    ("Main", "res://scenes/world/entry.tscn"),
+    ("Cave", "res://scenes/world/cave.tscn"),
+];
+
 pub struct LevelManager {}
 
 impl Default for LevelManager {
@@ -11,7 +26,42 @@ impl LevelManager {
         LevelManager {}
     }
 
+    pub fn get_entry_scene_id(&self) -> u32 {
+        ENTRY_SCENE_ID
+    }
+
     pub fn get_entry_scene_path(&self) -> String {
         "res://scenes/world/entry.tscn".to_string()
     }
+
+    pub fn get_scene_path(&self, scene_name: &str) -> Option<String> {
+        SCENE_PATHS
+            .iter()
+            .find(|(name, _)| *name == scene_name)
+            .map(|(_, path)| path.to_string())
+    }
+
+    /// Swaps the running Godot scene for the one rendering `scene_name`.
+    /// The new scene's `MultiplayerManager` boots the world again when it becomes ready.
+    pub fn change_scene(
+        &self,
+        scene_tree: &mut Gd<SceneTree>,
+        scene_name: &str,
+    ) -> Result<(), RustLibError> {
+        let scene_path = self
+            .get_scene_path(scene_name)
+            .ok_or(RustLibError::WorldSetup(format!(
+                "No Godot scene registered for world scene {}",
+                scene_name
+            )))?;
+
+        match scene_tree.change_scene_to_file(&scene_path) {
+            Error::OK => Ok(()),
+            error => {
+                godot_print!("Failed to change scene to {}: {:?}", scene_path, error);
+
+                Err(RustLibError::ResourceLoadError(scene_path))
+            }
+        }
+    }
 }
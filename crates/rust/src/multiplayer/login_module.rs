@@ -6,6 +6,8 @@ pub enum ConnectionState {
     Disconnected,
     Connected,
     LoggedIn,
+    /// Lost the connection while logged in, `ConnectionModule` is retrying.
+    Reconnecting,
     LoginFailed(String),
 }
 
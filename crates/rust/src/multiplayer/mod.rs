@@ -1,4 +1,5 @@
 pub mod bootstrap;
+pub mod clock_sync;
 pub mod connection_module;
 pub mod level_manager;
 pub mod login_module;
@@ -8,6 +9,7 @@ pub mod spacetimedb_manager;
 pub mod sync_time;
 
 pub use bootstrap::*;
+pub use clock_sync::*;
 pub use connection_module::*;
 pub use level_manager::*;
 pub use login_module::*;
@@ -4,7 +4,7 @@ use crate::*;
 use godot::classes::{Engine, INode, Node};
 use godot::prelude::*;
 
-use spacetimedb_sdk::Identity;
+use spacetimedb_sdk::{DbContext, Identity, Table};
 use std::collections::HashMap;
 
 pub const FRAME_RATE: f32 = 60.0;
@@ -13,6 +13,13 @@ pub const FRAME_RATE: f32 = 60.0;
 #[class(base=Node)]
 pub struct MultiplayerManager {
     remote_players: HashMap<Identity, Gd<RemotePlayerNode>>,
+    coins: HashMap<u64, Gd<CoinNode>>,
+    enemies: HashMap<u64, Gd<GreenSlimeNode>>,
+    dropped_items: HashMap<u64, Gd<DroppedItemNode>>,
+
+    /// Scene this node booted; a different `scene_id` on the local player row means we travelled.
+    scene_id: Option<u32>,
+    level_manager: LevelManager,
 
     #[base]
     base: Base<Node>,
@@ -23,6 +30,11 @@ impl INode for MultiplayerManager {
     fn init(base: Base<Node>) -> Self {
         Self {
             remote_players: HashMap::new(),
+            coins: HashMap::new(),
+            enemies: HashMap::new(),
+            dropped_items: HashMap::new(),
+            scene_id: None,
+            level_manager: LevelManager::new(),
             base,
         }
     }
@@ -49,15 +61,52 @@ impl INode for MultiplayerManager {
             return;
         };
 
+        let Ok(scene_id) = db_manager.get_current_scene_id() else {
+            godot_print!("Failed to get current scene");
+            return;
+        };
+
         let bootstrap = WorldBootstrap::new();
-        if let Err(result) = bootstrap.boot_player(&mut self.base_mut(), connection, player_name) {
+        if let Err(result) =
+            bootstrap.boot_player(&mut self.base_mut(), connection, player_name, scene_id)
+        {
             godot_print!("Failed to start bootstrap: {:?}", result);
         }
+
+        let coins = connection
+            .db
+            .coin()
+            .iter()
+            .filter(|coin| coin.scene_id == scene_id && coin.collected_by.is_none())
+            .collect::<Vec<_>>();
+
+        drop(db_manager);
+
+        // Everything queued so far is already reflected in the table snapshot.
+        CoinNode::take_events();
+        for coin in coins {
+            self.spawn_coin(&coin);
+        }
+
+        self.scene_id = Some(scene_id);
+        SpacetimeDBManager::finish_scene_transition();
     }
 }
 
 #[godot_api]
 impl MultiplayerManager {
+    /// Estimated server clock lead over the local clock, for debugging.
+    #[func]
+    fn get_clock_offset_ms(&self) -> f64 {
+        ClockSync::get_offset_micros().unwrap_or(0.0) / 1000.0
+    }
+
+    /// Smoothed reducer round trip time, for debugging.
+    #[func]
+    fn get_round_trip_ms(&self) -> f64 {
+        ClockSync::get_rtt_micros().unwrap_or(0.0) / 1000.0
+    }
+
     fn handle_multiplayer_updates(&mut self, _delta: f32) {
         {
             let Some(mut db_manager) = SpacetimeDBManager::get_write_connection() else {
@@ -70,7 +119,139 @@ impl MultiplayerManager {
             }
         }
 
+        self.check_scene_change();
+        self.sync_deaths();
         self.sync_remote_players();
+        self.sync_enemies();
+        self.sync_dropped_items();
+        self.sync_coins();
+    }
+
+    fn check_scene_change(&mut self) {
+        let Some(loaded_scene_id) = self.scene_id else {
+            return;
+        };
+
+        let target_scene = {
+            let Some(db_manager) = SpacetimeDBManager::get_read_connection() else {
+                return;
+            };
+
+            let Ok(Some(player)) = db_manager.get_local_player() else {
+                return;
+            };
+
+            if player.scene_id == loaded_scene_id {
+                return;
+            }
+
+            let Ok(connection) = db_manager.get_connection() else {
+                return;
+            };
+
+            match get_world_scene(connection, player.scene_id) {
+                Ok(scene) => scene,
+                Err(e) => {
+                    godot_print!("Failed to find target scene: {}", e);
+                    return;
+                }
+            }
+        };
+
+        godot_print!(
+            "Leaving scene {} for scene {} ({})",
+            loaded_scene_id,
+            target_scene.scene_id,
+            target_scene.name
+        );
+
+        self.leave_scene();
+
+        let Some(mut scene_tree) = self.base().get_tree() else {
+            godot_print!("Failed to get scene tree");
+            return;
+        };
+
+        if let Err(e) = self
+            .level_manager
+            .change_scene(&mut scene_tree, &target_scene.name)
+        {
+            godot_print!("Failed to load scene {}: {}", target_scene.name, e);
+        }
+    }
+
+    /// Tears down everything this node spawned for the scene it is leaving.
+    fn leave_scene(&mut self) {
+        self.scene_id = None;
+
+        let player_ids: Vec<Identity> = self.remote_players.keys().cloned().collect();
+        for player_id in player_ids {
+            self.remove_remote_player(player_id);
+        }
+
+        for (_, mut coin) in self.coins.drain() {
+            if coin.is_instance_valid() {
+                coin.queue_free();
+            }
+        }
+
+        for (_, mut enemy) in self.enemies.drain() {
+            enemy.queue_free();
+        }
+
+        for (_, mut dropped_item) in self.dropped_items.drain() {
+            dropped_item.queue_free();
+        }
+    }
+
+    fn sync_coins(&mut self) {
+        let events = CoinNode::take_events();
+        let Some(scene_id) = self.scene_id else {
+            return;
+        };
+
+        for event in events {
+            match event {
+                CoinEvent::Spawned(coin) => {
+                    if coin.scene_id == scene_id {
+                        self.spawn_coin(&coin);
+                    }
+                }
+                CoinEvent::Collected {
+                    coin_id,
+                    collected_by,
+                } => {
+                    let Some(mut coin) = self.coins.remove(&coin_id) else {
+                        continue;
+                    };
+
+                    godot_print!("Coin {} collected by {}", coin_id, collected_by);
+                    if coin.is_instance_valid() {
+                        coin.bind_mut().play_pickup();
+                    }
+                }
+                CoinEvent::Removed(coin_id) => {
+                    if let Some(mut coin) = self.coins.remove(&coin_id)
+                        && coin.is_instance_valid()
+                    {
+                        coin.queue_free();
+                    }
+                }
+            }
+        }
+    }
+
+    fn spawn_coin(&mut self, coin: &Coin) {
+        if self.coins.contains_key(&coin.coin_id) {
+            return;
+        }
+
+        match CoinNode::spawn_object(self.base_mut(), coin) {
+            Ok(coin_node) => {
+                self.coins.insert(coin.coin_id, coin_node);
+            }
+            Err(e) => godot_print!("Failed to spawn coin {}: {}", coin.coin_id, e),
+        }
     }
 
     fn sync_remote_players(&mut self) {
@@ -78,6 +259,11 @@ impl MultiplayerManager {
             return;
         };
 
+        // Right after a reconnect the cache is still empty; keep everyone in place.
+        if !db_manager.is_world_synced() {
+            return;
+        }
+
         let Ok(players) = db_manager.get_other_players() else {
             return;
         };
@@ -89,19 +275,26 @@ impl MultiplayerManager {
             if let Some(remote_player) = self.remote_players.get_mut(&player.identity) {
                 let position = Vector2::new(player.state.position.x, player.state.position.y);
 
-                remote_player.bind_mut().set_player_position(
+                let mut remote_player = remote_player.bind_mut();
+                remote_player.set_player_position(
                     player.state.direction,
                     player.state.is_jumping,
                     position,
+                    player.state_updated_at.to_micros_since_unix_epoch(),
                 );
+                remote_player.set_linkdead(player.presence == PlayerPresence::Linkdead);
 
                 continue;
             }
 
-            let Ok(remote_player) = RemotePlayerNode::spawn_object(self.base_mut(), &player) else {
+            let Ok(mut remote_player) = RemotePlayerNode::spawn_object(self.base_mut(), &player)
+            else {
                 godot_print!("Failed to spawn remote player");
                 return;
             };
+            remote_player
+                .bind_mut()
+                .set_linkdead(player.presence == PlayerPresence::Linkdead);
 
             self.remote_players.insert(player.identity, remote_player);
         }
@@ -124,4 +317,141 @@ impl MultiplayerManager {
             godot_print!("Removed remote player {}", player_id);
         }
     }
+
+    fn sync_enemies(&mut self) {
+        if self.scene_id.is_none() {
+            return;
+        }
+
+        let enemies = {
+            let Some(db_manager) = SpacetimeDBManager::get_read_connection() else {
+                return;
+            };
+
+            if !db_manager.is_world_synced() {
+                return;
+            }
+
+            let Ok(enemies) = db_manager.get_scene_enemies() else {
+                return;
+            };
+
+            enemies
+        };
+
+        let mut current_enemies = std::collections::HashSet::new();
+        for enemy in enemies {
+            current_enemies.insert(enemy.enemy_id);
+
+            if let Some(enemy_node) = self.enemies.get_mut(&enemy.enemy_id) {
+                enemy_node.bind_mut().set_enemy_state(&enemy);
+                continue;
+            }
+
+            let spawned = match enemy.kind {
+                EnemyKind::GreenSlime => GreenSlimeNode::spawn_object(self.base_mut(), &enemy),
+            };
+
+            match spawned {
+                Ok(enemy_node) => {
+                    self.enemies.insert(enemy.enemy_id, enemy_node);
+                }
+                Err(e) => godot_print!("Failed to spawn enemy {}: {}", enemy.enemy_id, e),
+            }
+        }
+
+        // Enemy rows only go away when the enemy dies.
+        self.enemies.retain(|enemy_id, enemy_node| {
+            if current_enemies.contains(enemy_id) {
+                return true;
+            }
+
+            enemy_node.bind_mut().play_death();
+            false
+        });
+    }
+
+    fn sync_dropped_items(&mut self) {
+        if self.scene_id.is_none() {
+            return;
+        }
+
+        let dropped_items = {
+            let Some(db_manager) = SpacetimeDBManager::get_read_connection() else {
+                return;
+            };
+
+            if !db_manager.is_world_synced() {
+                return;
+            }
+
+            let Ok(dropped_items) = db_manager.get_scene_dropped_items() else {
+                return;
+            };
+
+            dropped_items
+        };
+
+        let mut current_dropped_items = std::collections::HashSet::new();
+        for dropped in dropped_items {
+            current_dropped_items.insert(dropped.drop_id);
+
+            if self.dropped_items.contains_key(&dropped.drop_id) {
+                continue;
+            }
+
+            match DroppedItemNode::spawn_object(self.base_mut(), &dropped) {
+                Ok(dropped_node) => {
+                    self.dropped_items.insert(dropped.drop_id, dropped_node);
+                }
+                Err(e) => godot_print!("Failed to spawn dropped item {}: {}", dropped.drop_id, e),
+            }
+        }
+
+        self.dropped_items.retain(|drop_id, dropped_node| {
+            if current_dropped_items.contains(drop_id) {
+                return true;
+            }
+
+            dropped_node.queue_free();
+            false
+        });
+    }
+
+    fn sync_deaths(&mut self) {
+        let deaths = PlayerDeath::take_events();
+        if self.scene_id.is_none() || deaths.is_empty() {
+            return;
+        }
+
+        let local_identity = {
+            let Some(db_manager) = SpacetimeDBManager::get_read_connection() else {
+                return;
+            };
+
+            let Ok(connection) = db_manager.get_connection() else {
+                return;
+            };
+
+            connection.identity()
+        };
+
+        for death in deaths {
+            if death.identity == local_identity {
+                let local_player = self
+                    .base()
+                    .try_get_node_as::<LocalPlayerNode>(local_identity.to_string().as_str());
+
+                if let Some(mut local_player) = local_player {
+                    local_player.bind_mut().play_death(death.respawn_at.into());
+                }
+
+                continue;
+            }
+
+            if let Some(remote_player) = self.remote_players.get_mut(&death.identity) {
+                remote_player.bind_mut().play_death(death.died_at.into());
+            }
+        }
+    }
 }
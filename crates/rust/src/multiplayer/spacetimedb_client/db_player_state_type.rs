@@ -12,6 +12,7 @@ pub struct DbPlayerState {
     pub position: DbVector2,
     pub direction: i32,
     pub is_jumping: bool,
+    pub input_sequence: u32,
 }
 
 impl __sdk::InModule for DbPlayerState {
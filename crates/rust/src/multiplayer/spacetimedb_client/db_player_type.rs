@@ -5,6 +5,7 @@
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 use super::db_player_state_type::DbPlayerState;
+use super::player_presence_type::PlayerPresence;
 
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
@@ -12,7 +13,14 @@ pub struct DbPlayer {
     pub identity: __sdk::Identity,
     pub player_id: u32,
     pub name: String,
+    pub scene_id: u32,
     pub state: DbPlayerState,
+    pub state_updated_at: __sdk::Timestamp,
+    pub presence: PlayerPresence,
+    pub health: u32,
+    pub deaths: u32,
+    pub invulnerable_until: Option<__sdk::Timestamp>,
+    pub checkpoint_id: Option<u64>,
 }
 
 impl __sdk::InModule for DbPlayer {
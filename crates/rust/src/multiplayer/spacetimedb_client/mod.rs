@@ -6,49 +6,159 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod activate_checkpoint_reducer;
+pub mod change_scene_reducer;
+pub mod checkpoint_table;
+pub mod checkpoint_type;
+pub mod chest_opening_table;
+pub mod chest_opening_type;
+pub mod chest_table;
+pub mod chest_type;
+pub mod clock_probe_reducer;
+pub mod coin_respawn_timer_type;
 pub mod coin_table;
 pub mod coin_type;
+pub mod complete_scene_reducer;
 pub mod db_player_state_type;
 pub mod db_player_type;
 pub mod db_vector_2_type;
+pub mod drop_item_reducer;
+pub mod dropped_item_table;
+pub mod dropped_item_type;
+pub mod enemy_ai_timer_type;
+pub mod enemy_kind_type;
+pub mod enemy_respawn_timer_type;
+pub mod enemy_state_type;
+pub mod enemy_table;
+pub mod enemy_type;
+pub mod equip_item_reducer;
+pub mod expire_linkdead_reducer;
+pub mod grant_item_reducer;
+pub mod hazard_type;
 pub mod identity_connected_reducer;
 pub mod identity_disconnected_reducer;
+pub mod inventory_item_type;
+pub mod inventory_table;
+pub mod item_definition_table;
+pub mod item_definition_type;
+pub mod item_effect_type;
+pub mod item_kind_type;
+pub mod item_rarity_type;
+pub mod linkdead_timer_type;
+pub mod movement_path_type;
+pub mod open_chest_reducer;
+pub mod pick_up_item_reducer;
+pub mod platform_table;
+pub mod platform_type;
+pub mod player_presence_type;
 pub mod player_score_table;
 pub mod player_score_type;
 pub mod player_table;
+pub mod portal_table;
+pub mod portal_type;
 pub mod register_player_reducer;
+pub mod report_hazard_reducer;
+pub mod respawn_coin_reducer;
+pub mod scene_completion_table;
+pub mod scene_completion_type;
 pub mod send_player_state_reducer;
+pub mod stomp_enemy_reducer;
 pub mod try_collect_coin_reducer;
+pub mod unequip_item_reducer;
+pub mod update_enemies_reducer;
 pub mod update_timestamp_reducer;
+pub mod world_clock_table;
+pub mod world_clock_timer_type;
+pub mod world_clock_type;
 pub mod world_scene_table;
 pub mod world_scene_type;
 
+pub use activate_checkpoint_reducer::{
+    ActivateCheckpointCallbackId, activate_checkpoint, set_flags_for_activate_checkpoint,
+};
+pub use change_scene_reducer::{ChangeSceneCallbackId, change_scene, set_flags_for_change_scene};
+pub use checkpoint_table::*;
+pub use checkpoint_type::Checkpoint;
+pub use chest_opening_table::*;
+pub use chest_opening_type::ChestOpening;
+pub use chest_table::*;
+pub use chest_type::Chest;
+pub use clock_probe_reducer::{ClockProbeCallbackId, clock_probe, set_flags_for_clock_probe};
+pub use coin_respawn_timer_type::CoinRespawnTimer;
 pub use coin_table::*;
 pub use coin_type::Coin;
+pub use complete_scene_reducer::{
+    CompleteSceneCallbackId, complete_scene, set_flags_for_complete_scene,
+};
 pub use db_player_state_type::DbPlayerState;
 pub use db_player_type::DbPlayer;
 pub use db_vector_2_type::DbVector2;
+pub use drop_item_reducer::{DropItemCallbackId, drop_item, set_flags_for_drop_item};
+pub use dropped_item_table::*;
+pub use dropped_item_type::DroppedItem;
+pub use enemy_ai_timer_type::EnemyAiTimer;
+pub use enemy_kind_type::EnemyKind;
+pub use enemy_respawn_timer_type::EnemyRespawnTimer;
+pub use enemy_state_type::EnemyState;
+pub use enemy_table::*;
+pub use enemy_type::Enemy;
+pub use equip_item_reducer::{EquipItemCallbackId, equip_item, set_flags_for_equip_item};
+pub use expire_linkdead_reducer::{
+    ExpireLinkdeadCallbackId, expire_linkdead, set_flags_for_expire_linkdead,
+};
+pub use grant_item_reducer::{GrantItemCallbackId, grant_item, set_flags_for_grant_item};
+pub use hazard_type::Hazard;
 pub use identity_connected_reducer::{
     IdentityConnectedCallbackId, identity_connected, set_flags_for_identity_connected,
 };
 pub use identity_disconnected_reducer::{
     IdentityDisconnectedCallbackId, identity_disconnected, set_flags_for_identity_disconnected,
 };
+pub use inventory_item_type::InventoryItem;
+pub use inventory_table::*;
+pub use item_definition_table::*;
+pub use item_definition_type::ItemDefinition;
+pub use item_effect_type::ItemEffect;
+pub use item_kind_type::ItemKind;
+pub use item_rarity_type::ItemRarity;
+pub use linkdead_timer_type::LinkdeadTimer;
+pub use movement_path_type::MovementPath;
+pub use open_chest_reducer::{OpenChestCallbackId, open_chest, set_flags_for_open_chest};
+pub use pick_up_item_reducer::{PickUpItemCallbackId, pick_up_item, set_flags_for_pick_up_item};
+pub use platform_table::*;
+pub use platform_type::Platform;
+pub use player_presence_type::PlayerPresence;
 pub use player_score_table::*;
 pub use player_score_type::PlayerScore;
 pub use player_table::*;
+pub use portal_table::*;
+pub use portal_type::Portal;
 pub use register_player_reducer::{
     RegisterPlayerCallbackId, register_player, set_flags_for_register_player,
 };
+pub use report_hazard_reducer::{
+    ReportHazardCallbackId, report_hazard, set_flags_for_report_hazard,
+};
+pub use respawn_coin_reducer::{RespawnCoinCallbackId, respawn_coin, set_flags_for_respawn_coin};
+pub use scene_completion_table::*;
+pub use scene_completion_type::SceneCompletion;
 pub use send_player_state_reducer::{
     SendPlayerStateCallbackId, send_player_state, set_flags_for_send_player_state,
 };
+pub use stomp_enemy_reducer::{StompEnemyCallbackId, set_flags_for_stomp_enemy, stomp_enemy};
 pub use try_collect_coin_reducer::{
     TryCollectCoinCallbackId, set_flags_for_try_collect_coin, try_collect_coin,
 };
+pub use unequip_item_reducer::{UnequipItemCallbackId, set_flags_for_unequip_item, unequip_item};
+pub use update_enemies_reducer::{
+    UpdateEnemiesCallbackId, set_flags_for_update_enemies, update_enemies,
+};
 pub use update_timestamp_reducer::{
     UpdateTimestampCallbackId, set_flags_for_update_timestamp, update_timestamp,
 };
+pub use world_clock_table::*;
+pub use world_clock_timer_type::WorldClockTimer;
+pub use world_clock_type::WorldClock;
 pub use world_scene_table::*;
 pub use world_scene_type::WorldScene;
 
@@ -60,12 +170,67 @@ pub use world_scene_type::WorldScene;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
+    ActivateCheckpoint {
+        checkpoint_id: u64,
+    },
+    ChangeScene {
+        portal_name: String,
+    },
+    ClockProbe {
+        client_time: __sdk::Timestamp,
+    },
+    CompleteScene,
+    DropItem {
+        inventory_id: u64,
+        quantity: u32,
+    },
+    EquipItem {
+        inventory_id: u64,
+    },
+    ExpireLinkdead {
+        timer: LinkdeadTimer,
+    },
+    GrantItem {
+        owner: __sdk::Identity,
+        item_name: String,
+        quantity: u32,
+    },
     IdentityConnected,
     IdentityDisconnected,
-    RegisterPlayer { name: String, scene_id: u32 },
-    SendPlayerState { state: DbPlayerState },
-    TryCollectCoin { position: DbVector2 },
-    UpdateTimestamp,
+    OpenChest {
+        chest_id: u64,
+    },
+    PickUpItem {
+        drop_id: u64,
+    },
+    RegisterPlayer {
+        name: String,
+        scene_id: u32,
+    },
+    ReportHazard {
+        hazard: Hazard,
+    },
+    RespawnCoin {
+        timer: CoinRespawnTimer,
+    },
+    SendPlayerState {
+        state: DbPlayerState,
+    },
+    StompEnemy {
+        enemy_id: u64,
+    },
+    TryCollectCoin {
+        coin_id: u64,
+    },
+    UnequipItem {
+        inventory_id: u64,
+    },
+    UpdateEnemies {
+        timer: EnemyAiTimer,
+    },
+    UpdateTimestamp {
+        timer: WorldClockTimer,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -75,12 +240,28 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::ActivateCheckpoint { .. } => "activate_checkpoint",
+            Reducer::ChangeScene { .. } => "change_scene",
+            Reducer::ClockProbe { .. } => "clock_probe",
+            Reducer::CompleteScene => "complete_scene",
+            Reducer::DropItem { .. } => "drop_item",
+            Reducer::EquipItem { .. } => "equip_item",
+            Reducer::ExpireLinkdead { .. } => "expire_linkdead",
+            Reducer::GrantItem { .. } => "grant_item",
             Reducer::IdentityConnected => "identity_connected",
             Reducer::IdentityDisconnected => "identity_disconnected",
+            Reducer::OpenChest { .. } => "open_chest",
+            Reducer::PickUpItem { .. } => "pick_up_item",
             Reducer::RegisterPlayer { .. } => "register_player",
+            Reducer::ReportHazard { .. } => "report_hazard",
+            Reducer::RespawnCoin { .. } => "respawn_coin",
+            Reducer::RespawnEnemy { .. } => "respawn_enemy",
             Reducer::SendPlayerState { .. } => "send_player_state",
+            Reducer::StompEnemy { .. } => "stomp_enemy",
             Reducer::TryCollectCoin { .. } => "try_collect_coin",
-            Reducer::UpdateTimestamp => "update_timestamp",
+            Reducer::UnequipItem { .. } => "unequip_item",
+            Reducer::UpdateEnemies { .. } => "update_enemies",
+            Reducer::UpdateTimestamp { .. } => "update_timestamp",
         }
     }
 }
@@ -88,6 +269,53 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "activate_checkpoint" => Ok(__sdk::parse_reducer_args::<
+                activate_checkpoint_reducer::ActivateCheckpointArgs,
+            >("activate_checkpoint", &value.args)?
+            .into()),
+            "change_scene" => Ok(
+                __sdk::parse_reducer_args::<change_scene_reducer::ChangeSceneArgs>(
+                    "change_scene",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "clock_probe" => Ok(
+                __sdk::parse_reducer_args::<clock_probe_reducer::ClockProbeArgs>(
+                    "clock_probe",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "complete_scene" => Ok(__sdk::parse_reducer_args::<
+                complete_scene_reducer::CompleteSceneArgs,
+            >("complete_scene", &value.args)?
+            .into()),
+            "drop_item" => Ok(
+                __sdk::parse_reducer_args::<drop_item_reducer::DropItemArgs>(
+                    "drop_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "equip_item" => Ok(
+                __sdk::parse_reducer_args::<equip_item_reducer::EquipItemArgs>(
+                    "equip_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "expire_linkdead" => Ok(__sdk::parse_reducer_args::<
+                expire_linkdead_reducer::ExpireLinkdeadArgs,
+            >("expire_linkdead", &value.args)?
+            .into()),
+            "grant_item" => Ok(
+                __sdk::parse_reducer_args::<grant_item_reducer::GrantItemArgs>(
+                    "grant_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "identity_connected" => Ok(__sdk::parse_reducer_args::<
                 identity_connected_reducer::IdentityConnectedArgs,
             >("identity_connected", &value.args)?
@@ -96,18 +324,65 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 identity_disconnected_reducer::IdentityDisconnectedArgs,
             >("identity_disconnected", &value.args)?
             .into()),
+            "open_chest" => Ok(
+                __sdk::parse_reducer_args::<open_chest_reducer::OpenChestArgs>(
+                    "open_chest",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "pick_up_item" => Ok(
+                __sdk::parse_reducer_args::<pick_up_item_reducer::PickUpItemArgs>(
+                    "pick_up_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "register_player" => Ok(__sdk::parse_reducer_args::<
                 register_player_reducer::RegisterPlayerArgs,
             >("register_player", &value.args)?
             .into()),
+            "report_hazard" => Ok(__sdk::parse_reducer_args::<
+                report_hazard_reducer::ReportHazardArgs,
+            >("report_hazard", &value.args)?
+            .into()),
+            "respawn_coin" => Ok(
+                __sdk::parse_reducer_args::<respawn_coin_reducer::RespawnCoinArgs>(
+                    "respawn_coin",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "respawn_enemy" => Ok(__sdk::parse_reducer_args::<
+                respawn_enemy_reducer::RespawnEnemyArgs,
+            >("respawn_enemy", &value.args)?
+            .into()),
             "send_player_state" => Ok(__sdk::parse_reducer_args::<
                 send_player_state_reducer::SendPlayerStateArgs,
             >("send_player_state", &value.args)?
             .into()),
+            "stomp_enemy" => Ok(
+                __sdk::parse_reducer_args::<stomp_enemy_reducer::StompEnemyArgs>(
+                    "stomp_enemy",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "try_collect_coin" => Ok(__sdk::parse_reducer_args::<
                 try_collect_coin_reducer::TryCollectCoinArgs,
             >("try_collect_coin", &value.args)?
             .into()),
+            "unequip_item" => Ok(
+                __sdk::parse_reducer_args::<unequip_item_reducer::UnequipItemArgs>(
+                    "unequip_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "update_enemies" => Ok(__sdk::parse_reducer_args::<
+                update_enemies_reducer::UpdateEnemiesArgs,
+            >("update_enemies", &value.args)?
+            .into()),
             "update_timestamp" => Ok(__sdk::parse_reducer_args::<
                 update_timestamp_reducer::UpdateTimestampArgs,
             >("update_timestamp", &value.args)?
@@ -126,9 +401,20 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    checkpoint: __sdk::TableUpdate<Checkpoint>,
+    chest: __sdk::TableUpdate<Chest>,
+    chest_opening: __sdk::TableUpdate<ChestOpening>,
     coin: __sdk::TableUpdate<Coin>,
+    dropped_item: __sdk::TableUpdate<DroppedItem>,
+    enemy: __sdk::TableUpdate<Enemy>,
+    inventory: __sdk::TableUpdate<InventoryItem>,
+    item_definition: __sdk::TableUpdate<ItemDefinition>,
+    platform: __sdk::TableUpdate<Platform>,
     player: __sdk::TableUpdate<DbPlayer>,
     player_score: __sdk::TableUpdate<PlayerScore>,
+    portal: __sdk::TableUpdate<Portal>,
+    scene_completion: __sdk::TableUpdate<SceneCompletion>,
+    world_clock: __sdk::TableUpdate<WorldClock>,
     world_scene: __sdk::TableUpdate<WorldScene>,
 }
 
@@ -138,15 +424,48 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "checkpoint" => db_update
+                    .checkpoint
+                    .append(checkpoint_table::parse_table_update(table_update)?),
+                "chest" => db_update
+                    .chest
+                    .append(chest_table::parse_table_update(table_update)?),
+                "chest_opening" => db_update
+                    .chest_opening
+                    .append(chest_opening_table::parse_table_update(table_update)?),
                 "coin" => db_update
                     .coin
                     .append(coin_table::parse_table_update(table_update)?),
+                "dropped_item" => db_update
+                    .dropped_item
+                    .append(dropped_item_table::parse_table_update(table_update)?),
+                "enemy" => db_update
+                    .enemy
+                    .append(enemy_table::parse_table_update(table_update)?),
+                "inventory" => db_update
+                    .inventory
+                    .append(inventory_table::parse_table_update(table_update)?),
+                "item_definition" => db_update
+                    .item_definition
+                    .append(item_definition_table::parse_table_update(table_update)?),
+                "platform" => db_update
+                    .platform
+                    .append(platform_table::parse_table_update(table_update)?),
                 "player" => db_update
                     .player
                     .append(player_table::parse_table_update(table_update)?),
                 "player_score" => db_update
                     .player_score
                     .append(player_score_table::parse_table_update(table_update)?),
+                "portal" => db_update
+                    .portal
+                    .append(portal_table::parse_table_update(table_update)?),
+                "scene_completion" => db_update
+                    .scene_completion
+                    .append(scene_completion_table::parse_table_update(table_update)?),
+                "world_clock" => db_update
+                    .world_clock
+                    .append(world_clock_table::parse_table_update(table_update)?),
                 "world_scene" => db_update
                     .world_scene
                     .append(world_scene_table::parse_table_update(table_update)?),
@@ -176,15 +495,48 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.checkpoint = cache
+            .apply_diff_to_table::<Checkpoint>("checkpoint", &self.checkpoint)
+            .with_updates_by_pk(|row| &row.checkpoint_id);
+        diff.chest = cache
+            .apply_diff_to_table::<Chest>("chest", &self.chest)
+            .with_updates_by_pk(|row| &row.chest_id);
+        diff.chest_opening = cache
+            .apply_diff_to_table::<ChestOpening>("chest_opening", &self.chest_opening)
+            .with_updates_by_pk(|row| &row.opening_id);
         diff.coin = cache
             .apply_diff_to_table::<Coin>("coin", &self.coin)
             .with_updates_by_pk(|row| &row.coin_id);
+        diff.dropped_item = cache
+            .apply_diff_to_table::<DroppedItem>("dropped_item", &self.dropped_item)
+            .with_updates_by_pk(|row| &row.drop_id);
+        diff.enemy = cache
+            .apply_diff_to_table::<Enemy>("enemy", &self.enemy)
+            .with_updates_by_pk(|row| &row.enemy_id);
+        diff.inventory = cache
+            .apply_diff_to_table::<InventoryItem>("inventory", &self.inventory)
+            .with_updates_by_pk(|row| &row.inventory_id);
+        diff.item_definition = cache
+            .apply_diff_to_table::<ItemDefinition>("item_definition", &self.item_definition)
+            .with_updates_by_pk(|row| &row.item_id);
+        diff.platform = cache
+            .apply_diff_to_table::<Platform>("platform", &self.platform)
+            .with_updates_by_pk(|row| &row.platform_id);
         diff.player = cache
             .apply_diff_to_table::<DbPlayer>("player", &self.player)
             .with_updates_by_pk(|row| &row.identity);
         diff.player_score = cache
             .apply_diff_to_table::<PlayerScore>("player_score", &self.player_score)
             .with_updates_by_pk(|row| &row.score_id);
+        diff.portal = cache
+            .apply_diff_to_table::<Portal>("portal", &self.portal)
+            .with_updates_by_pk(|row| &row.portal_id);
+        diff.scene_completion = cache
+            .apply_diff_to_table::<SceneCompletion>("scene_completion", &self.scene_completion)
+            .with_updates_by_pk(|row| &row.completion_id);
+        diff.world_clock = cache
+            .apply_diff_to_table::<WorldClock>("world_clock", &self.world_clock)
+            .with_updates_by_pk(|row| &row.scene_id);
         diff.world_scene = cache
             .apply_diff_to_table::<WorldScene>("world_scene", &self.world_scene)
             .with_updates_by_pk(|row| &row.scene_id);
@@ -197,9 +549,20 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    checkpoint: __sdk::TableAppliedDiff<'r, Checkpoint>,
+    chest: __sdk::TableAppliedDiff<'r, Chest>,
+    chest_opening: __sdk::TableAppliedDiff<'r, ChestOpening>,
     coin: __sdk::TableAppliedDiff<'r, Coin>,
+    dropped_item: __sdk::TableAppliedDiff<'r, DroppedItem>,
+    enemy: __sdk::TableAppliedDiff<'r, Enemy>,
+    inventory: __sdk::TableAppliedDiff<'r, InventoryItem>,
+    item_definition: __sdk::TableAppliedDiff<'r, ItemDefinition>,
+    platform: __sdk::TableAppliedDiff<'r, Platform>,
     player: __sdk::TableAppliedDiff<'r, DbPlayer>,
     player_score: __sdk::TableAppliedDiff<'r, PlayerScore>,
+    portal: __sdk::TableAppliedDiff<'r, Portal>,
+    scene_completion: __sdk::TableAppliedDiff<'r, SceneCompletion>,
+    world_clock: __sdk::TableAppliedDiff<'r, WorldClock>,
     world_scene: __sdk::TableAppliedDiff<'r, WorldScene>,
 }
 
@@ -213,13 +576,40 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Checkpoint>("checkpoint", &self.checkpoint, event);
+        callbacks.invoke_table_row_callbacks::<Chest>("chest", &self.chest, event);
+        callbacks.invoke_table_row_callbacks::<ChestOpening>(
+            "chest_opening",
+            &self.chest_opening,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Coin>("coin", &self.coin, event);
+        callbacks.invoke_table_row_callbacks::<DroppedItem>(
+            "dropped_item",
+            &self.dropped_item,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Enemy>("enemy", &self.enemy, event);
+        callbacks.invoke_table_row_callbacks::<InventoryItem>("inventory", &self.inventory, event);
+        callbacks.invoke_table_row_callbacks::<ItemDefinition>(
+            "item_definition",
+            &self.item_definition,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Platform>("platform", &self.platform, event);
         callbacks.invoke_table_row_callbacks::<DbPlayer>("player", &self.player, event);
         callbacks.invoke_table_row_callbacks::<PlayerScore>(
             "player_score",
             &self.player_score,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Portal>("portal", &self.portal, event);
+        callbacks.invoke_table_row_callbacks::<SceneCompletion>(
+            "scene_completion",
+            &self.scene_completion,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<WorldClock>("world_clock", &self.world_clock, event);
         callbacks.invoke_table_row_callbacks::<WorldScene>("world_scene", &self.world_scene, event);
     }
 }
@@ -811,9 +1201,20 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        checkpoint_table::register_table(client_cache);
+        chest_table::register_table(client_cache);
+        chest_opening_table::register_table(client_cache);
         coin_table::register_table(client_cache);
+        dropped_item_table::register_table(client_cache);
+        enemy_table::register_table(client_cache);
+        inventory_table::register_table(client_cache);
+        item_definition_table::register_table(client_cache);
+        platform_table::register_table(client_cache);
         player_table::register_table(client_cache);
         player_score_table::register_table(client_cache);
+        portal_table::register_table(client_cache);
+        scene_completion_table::register_table(client_cache);
+        world_clock_table::register_table(client_cache);
         world_scene_table::register_table(client_cache);
     }
 }
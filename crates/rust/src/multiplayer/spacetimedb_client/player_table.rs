@@ -4,6 +4,7 @@
 #![allow(unused, clippy::all)]
 use super::db_player_state_type::DbPlayerState;
 use super::db_player_type::DbPlayer;
+use super::player_presence_type::PlayerPresence;
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 /// Table handle for the table `player`.
@@ -4,18 +4,16 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
-use super::db_vector_2_type::DbVector2;
-
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub(super) struct TryCollectCoinArgs {
-    pub position: DbVector2,
+    pub coin_id: u64,
 }
 
 impl From<TryCollectCoinArgs> for super::Reducer {
     fn from(args: TryCollectCoinArgs) -> Self {
         Self::TryCollectCoin {
-            position: args.position,
+            coin_id: args.coin_id,
         }
     }
 }
@@ -36,7 +34,7 @@ pub trait try_collect_coin {
     /// This method returns immediately, and errors only if we are unable to send the request.
     /// The reducer will run asynchronously in the future,
     ///  and its status can be observed by listening for [`Self::on_try_collect_coin`] callbacks.
-    fn try_collect_coin(&self, position: DbVector2) -> __sdk::Result<()>;
+    fn try_collect_coin(&self, coin_id: u64) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `try_collect_coin`.
     ///
     /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
@@ -46,7 +44,7 @@ pub trait try_collect_coin {
     /// to cancel the callback.
     fn on_try_collect_coin(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext, &DbVector2) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &u64) + Send + 'static,
     ) -> TryCollectCoinCallbackId;
     /// Cancel a callback previously registered by [`Self::on_try_collect_coin`],
     /// causing it not to run in the future.
@@ -54,13 +52,13 @@ pub trait try_collect_coin {
 }
 
 impl try_collect_coin for super::RemoteReducers {
-    fn try_collect_coin(&self, position: DbVector2) -> __sdk::Result<()> {
+    fn try_collect_coin(&self, coin_id: u64) -> __sdk::Result<()> {
         self.imp
-            .call_reducer("try_collect_coin", TryCollectCoinArgs { position })
+            .call_reducer("try_collect_coin", TryCollectCoinArgs { coin_id })
     }
     fn on_try_collect_coin(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext, &DbVector2) + Send + 'static,
+        mut callback: impl FnMut(&super::ReducerEventContext, &u64) + Send + 'static,
     ) -> TryCollectCoinCallbackId {
         TryCollectCoinCallbackId(self.imp.on_reducer(
             "try_collect_coin",
@@ -68,7 +66,7 @@ impl try_collect_coin for super::RemoteReducers {
                 let super::ReducerEventContext {
                     event:
                         __sdk::ReducerEvent {
-                            reducer: super::Reducer::TryCollectCoin { position },
+                            reducer: super::Reducer::TryCollectCoin { coin_id },
                             ..
                         },
                     ..
@@ -76,7 +74,7 @@ impl try_collect_coin for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx, position)
+                callback(ctx, coin_id)
             }),
         ))
     }
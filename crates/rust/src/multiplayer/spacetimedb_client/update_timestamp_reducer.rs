@@ -4,13 +4,17 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::world_clock_timer_type::WorldClockTimer;
+
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
-pub(super) struct UpdateTimestampArgs {}
+pub(super) struct UpdateTimestampArgs {
+    pub timer: WorldClockTimer,
+}
 
 impl From<UpdateTimestampArgs> for super::Reducer {
     fn from(args: UpdateTimestampArgs) -> Self {
-        Self::UpdateTimestamp
+        Self::UpdateTimestamp { timer: args.timer }
     }
 }
 
@@ -30,7 +34,7 @@ pub trait update_timestamp {
     /// This method returns immediately, and errors only if we are unable to send the request.
     /// The reducer will run asynchronously in the future,
     ///  and its status can be observed by listening for [`Self::on_update_timestamp`] callbacks.
-    fn update_timestamp(&self) -> __sdk::Result<()>;
+    fn update_timestamp(&self, timer: WorldClockTimer) -> __sdk::Result<()>;
     /// Register a callback to run whenever we are notified of an invocation of the reducer `update_timestamp`.
     ///
     /// Callbacks should inspect the [`__sdk::ReducerEvent`] contained in the [`super::ReducerEventContext`]
@@ -40,7 +44,7 @@ pub trait update_timestamp {
     /// to cancel the callback.
     fn on_update_timestamp(
         &self,
-        callback: impl FnMut(&super::ReducerEventContext) + Send + 'static,
+        callback: impl FnMut(&super::ReducerEventContext, &WorldClockTimer) + Send + 'static,
     ) -> UpdateTimestampCallbackId;
     /// Cancel a callback previously registered by [`Self::on_update_timestamp`],
     /// causing it not to run in the future.
@@ -48,13 +52,13 @@ pub trait update_timestamp {
 }
 
 impl update_timestamp for super::RemoteReducers {
-    fn update_timestamp(&self) -> __sdk::Result<()> {
+    fn update_timestamp(&self, timer: WorldClockTimer) -> __sdk::Result<()> {
         self.imp
-            .call_reducer("update_timestamp", UpdateTimestampArgs {})
+            .call_reducer("update_timestamp", UpdateTimestampArgs { timer })
     }
     fn on_update_timestamp(
         &self,
-        mut callback: impl FnMut(&super::ReducerEventContext) + Send + 'static,
+        mut callback: impl FnMut(&super::ReducerEventContext, &WorldClockTimer) + Send + 'static,
     ) -> UpdateTimestampCallbackId {
         UpdateTimestampCallbackId(self.imp.on_reducer(
             "update_timestamp",
@@ -62,7 +66,7 @@ impl update_timestamp for super::RemoteReducers {
                 let super::ReducerEventContext {
                     event:
                         __sdk::ReducerEvent {
-                            reducer: super::Reducer::UpdateTimestamp {},
+                            reducer: super::Reducer::UpdateTimestamp { timer },
                             ..
                         },
                     ..
@@ -70,7 +74,7 @@ impl update_timestamp for super::RemoteReducers {
                 else {
                     unreachable!()
                 };
-                callback(ctx)
+                callback(ctx, timer)
             }),
         ))
     }
@@ -11,9 +11,12 @@ use super::db_vector_2_type::DbVector2;
 pub struct WorldScene {
     pub scene_id: u32,
     pub name: String,
-    pub creation_time: __sdk::Timestamp,
-    pub last_update_time: __sdk::Timestamp,
     pub spawn_point: DbVector2,
+    pub coin_respawn_delay: __sdk::TimeDuration,
+    pub coin_pickup_radius: f32,
+    pub goal: Option<DbVector2>,
+    pub coin_loot_table: Option<String>,
+    pub completion_loot_table: Option<String>,
 }
 
 impl __sdk::InModule for WorldScene {
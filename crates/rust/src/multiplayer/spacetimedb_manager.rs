@@ -2,16 +2,25 @@ use crate::multiplayer::connection_module::ConnectionModule;
 use crate::register_player_reducer::register_player;
 
 use crate::{
-    CoinNode, ConnectionState, DbConnection, DbPlayer, DbPlayerState, GameManager, GreenSlimeNode,
-    LocalPlayerNode, LoginModule, PlatformNode, RustLibError, WorldBootstrap, send_player_state,
-    try_collect_coin,
+    CheckpointArea, ChestNode, ClockSync, CoinNode, ConnectionState, DbConnection, DbPlayer,
+    DbPlayerState, DroppedItem, DroppedItemNode, Enemy, GameManager, GoalArea, GreenSlimeNode,
+    Hazard, InventoryItem, InventoryPanel, ItemDefinition, ItemEffect, LocalPlayerNode,
+    LoginModule, PlatformNode, PlayerDeath, PlayerPresence, PortalArea, ReconnectStatus,
+    RustLibError, WorldBootstrap, activate_checkpoint, change_scene, complete_scene, drop_item,
+    equip_item, open_chest, pick_up_item, report_hazard, send_player_state, stomp_enemy,
+    try_collect_coin, unequip_item,
+};
+use crate::{
+    CheckpointTableAccess, ChestOpeningTableAccess, DroppedItemTableAccess, EnemyTableAccess,
+    InventoryTableAccess, ItemDefinitionTableAccess, PlayerTableAccess, SceneCompletionTableAccess,
+    WorldSceneTableAccess,
 };
-use crate::{DbVector2, PlayerTableAccess, WorldSceneTableAccess};
 
 use godot::prelude::*;
 
 use spacetimedb_sdk::{DbContext, Error, Table};
 
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use lazy_static::lazy_static;
@@ -21,6 +30,8 @@ lazy_static! {
         Arc::new(RwLock::new(SpacetimeDBManager::new()));
     pub static ref REGISTRATION_STATE: Arc<Mutex<RegistrationState>> =
         Arc::new(Mutex::new(RegistrationState::default()));
+    pub static ref SCENE_TRANSITION_STATE: Arc<Mutex<SceneTransitionState>> =
+        Arc::new(Mutex::new(SceneTransitionState::default()));
 }
 
 #[derive(Default)]
@@ -31,10 +42,20 @@ pub enum RegistrationState {
     RegistrationFailed(String),
 }
 
+#[derive(Default, Clone, PartialEq, Debug)]
+pub enum SceneTransitionState {
+    #[default]
+    Idle,
+    /// A `change_scene` call is in flight or committed, but the new scene is not booted yet.
+    Requested(String),
+}
+
 #[derive(Default)]
 pub struct SpacetimeDBManager {
     pub login_module: LoginModule,
     connection_module: ConnectionModule,
+    /// Last input sequence sent; kept here so it survives scene changes.
+    input_sequence: AtomicU32,
 }
 
 impl SpacetimeDBManager {
@@ -42,6 +63,7 @@ impl SpacetimeDBManager {
         Self {
             connection_module: ConnectionModule::new(),
             login_module: LoginModule::new(),
+            input_sequence: AtomicU32::new(0),
         }
     }
 
@@ -75,7 +97,6 @@ impl SpacetimeDBManager {
         self.connect_to_server(username)?;
         self.register_subscribers()?;
 
-        self.login_module.set_scene_id(1);
         self.login_module.set_player_name(username.to_string());
 
         *self.login_module.get_state_mut() = ConnectionState::Connected;
@@ -94,12 +115,20 @@ impl SpacetimeDBManager {
     fn register_subscribers(&mut self) -> Result<(), RustLibError> {
         let connection = self.connection_module.get_connection()?;
 
+        CheckpointArea::setup_multiplayer(connection);
+        ChestNode::setup_multiplayer(connection);
+        ClockSync::setup_multiplayer(connection);
         CoinNode::setup_multiplayer(connection);
+        DroppedItemNode::setup_multiplayer(connection);
         GameManager::setup_multiplayer(connection);
+        GoalArea::setup_multiplayer(connection);
         PlatformNode::setup_multiplayer(connection);
+        PlayerDeath::setup_multiplayer(connection);
         GreenSlimeNode::setup_multiplayer(connection);
+        InventoryPanel::setup_multiplayer(connection);
         WorldBootstrap::setup_multiplayer(connection);
         LocalPlayerNode::setup_multiplayer(connection, REGISTRATION_STATE.clone());
+        PortalArea::setup_multiplayer(connection, SCENE_TRANSITION_STATE.clone());
 
         Ok(())
     }
@@ -107,19 +136,30 @@ impl SpacetimeDBManager {
 
 impl SpacetimeDBManager {
     pub fn tick(&mut self) -> Result<(), RustLibError> {
-        if self.login_module.get_state() == &ConnectionState::Disconnected {
-            return Ok(());
+        match self.login_module.get_state() {
+            ConnectionState::Disconnected => return Ok(()),
+            ConnectionState::Reconnecting => return self.tick_reconnect(),
+            _ => {}
         }
 
         let connection: &DbConnection = self.connection_module.get_connection()?;
 
         match connection.frame_tick() {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                ClockSync::tick(connection);
+
+                Ok(())
+            }
             Err(e) => match e {
                 Error::Disconnected => {
                     godot_print!("Disconnected from server");
 
-                    *self.login_module.get_state_mut() = ConnectionState::Disconnected;
+                    if self.login_module.get_state() == &ConnectionState::LoggedIn {
+                        self.connection_module.begin_reconnect();
+                        *self.login_module.get_state_mut() = ConnectionState::Reconnecting;
+                    } else {
+                        *self.login_module.get_state_mut() = ConnectionState::Disconnected;
+                    }
 
                     Ok(())
                 }
@@ -131,6 +171,55 @@ impl SpacetimeDBManager {
             },
         }
     }
+
+    /// Drives `ConnectionModule`'s backoff and resumes the player in place once
+    /// the connection is back, so the loaded scene is kept.
+    fn tick_reconnect(&mut self) -> Result<(), RustLibError> {
+        match self.connection_module.poll_reconnect() {
+            Ok(false) => Ok(()),
+            Ok(true) => {
+                self.register_subscribers()?;
+
+                let (Some(player_name), Some(scene_id)) = (
+                    self.login_module.get_player_name().map(str::to_string),
+                    self.login_module.get_scene_id(),
+                ) else {
+                    return Err(RustLibError::WrongConnectionState(
+                        "Reconnected without a registered player.".to_string(),
+                    ));
+                };
+
+                // The server resumes the existing row, the scene id only matters for new players.
+                let connection = self.connection_module.get_connection()?;
+                connection
+                    .reducers
+                    .register_player(player_name, scene_id)
+                    .map_err(|e| RustLibError::SpacetimeSDK { source: e })?;
+
+                *self.login_module.get_state_mut() = ConnectionState::LoggedIn;
+
+                Ok(())
+            }
+            Err(e) => {
+                *self.login_module.get_state_mut() = ConnectionState::Disconnected;
+
+                Err(e)
+            }
+        }
+    }
+
+    pub fn is_reconnecting(&self) -> bool {
+        self.login_module.get_state() == &ConnectionState::Reconnecting
+    }
+
+    pub fn get_reconnect_status(&self) -> ReconnectStatus {
+        self.connection_module.get_reconnect_status().clone()
+    }
+
+    /// Whether the client cache holds the local player yet; false right after a reconnect.
+    pub fn is_world_synced(&self) -> bool {
+        matches!(self.get_local_player(), Ok(Some(_)))
+    }
 }
 
 impl SpacetimeDBManager {
@@ -140,6 +229,8 @@ impl SpacetimeDBManager {
             Ok(_) => {
                 godot_print!("Player registration request sent successfully!");
 
+                self.login_module.set_scene_id(scene_id);
+
                 Ok(())
             }
             Err(e) => {
@@ -150,23 +241,61 @@ impl SpacetimeDBManager {
         }
     }
 
+    /// Where the local player respawns: its last checkpoint in the current
+    /// scene, or the scene's spawn point.
     pub fn get_spawn_point(&self) -> Result<Option<Vector2>, RustLibError> {
         let connection = self.connection_module.get_connection()?;
-        let scene_id = self
-            .login_module
-            .get_scene_id()
-            .ok_or(RustLibError::WorldSetup(
-                "Expected scene id to be in the Login Module.".to_string(),
-            ))?;
+        let scene_id = self.get_current_scene_id()?;
+
+        let checkpoint = self
+            .get_local_player()?
+            .and_then(|player| player.checkpoint_id)
+            .and_then(|checkpoint_id| {
+                connection
+                    .db()
+                    .checkpoint()
+                    .checkpoint_id()
+                    .find(&checkpoint_id)
+            })
+            .filter(|checkpoint| checkpoint.scene_id == scene_id);
+        if let Some(checkpoint) = checkpoint {
+            return Ok(Some(Vector2::new(
+                checkpoint.position.x,
+                checkpoint.position.y,
+            )));
+        }
 
         Ok(connection
             .db()
             .world_scene()
-            .iter()
-            .find(|x| x.scene_id == scene_id)
+            .scene_id()
+            .find(&scene_id)
             .map(|scene| Vector2::new(scene.spawn_point.x, scene.spawn_point.y)))
     }
 
+    pub fn get_local_player(&self) -> Result<Option<DbPlayer>, RustLibError> {
+        let connection = self.connection_module.get_connection()?;
+
+        Ok(connection
+            .db()
+            .player()
+            .identity()
+            .find(&connection.identity()))
+    }
+
+    /// Scene of the local player's row, falling back to the scene requested at registration.
+    pub fn get_current_scene_id(&self) -> Result<u32, RustLibError> {
+        if let Some(player) = self.get_local_player()? {
+            return Ok(player.scene_id);
+        }
+
+        self.login_module
+            .get_scene_id()
+            .ok_or(RustLibError::WorldSetup(
+                "Expected scene id to be in the Login Module.".to_string(),
+            ))
+    }
+
     pub fn check_and_login(&mut self) -> bool {
         let registration_state = REGISTRATION_STATE.lock().unwrap();
         match &*registration_state {
@@ -198,22 +327,115 @@ impl SpacetimeDBManager {
 
     pub fn get_other_players(&self) -> Result<Vec<DbPlayer>, RustLibError> {
         let connection = self.connection_module.get_connection()?;
+        let scene_id = self.get_current_scene_id()?;
+
         Ok(connection
             .db()
             .player()
             .iter()
-            .filter(|x| x.identity != connection.identity())
+            .filter(|x| {
+                x.identity != connection.identity()
+                    && x.scene_id == scene_id
+                    && x.presence != PlayerPresence::Offline
+            })
             .collect())
     }
+
+    pub fn get_scene_enemies(&self) -> Result<Vec<Enemy>, RustLibError> {
+        let connection = self.connection_module.get_connection()?;
+        let scene_id = self.get_current_scene_id()?;
+
+        Ok(connection
+            .db()
+            .enemy()
+            .iter()
+            .filter(|enemy| enemy.scene_id == scene_id)
+            .collect())
+    }
+
+    pub fn get_scene_dropped_items(&self) -> Result<Vec<DroppedItem>, RustLibError> {
+        let connection = self.connection_module.get_connection()?;
+        let scene_id = self.get_current_scene_id()?;
+
+        Ok(connection
+            .db()
+            .dropped_item()
+            .iter()
+            .filter(|dropped| dropped.scene_id == scene_id)
+            .collect())
+    }
+
+    /// The local player's stacks, in the order they were first filled.
+    pub fn get_local_inventory(&self) -> Result<Vec<InventoryItem>, RustLibError> {
+        let connection = self.connection_module.get_connection()?;
+
+        let mut stacks: Vec<InventoryItem> = connection
+            .db()
+            .inventory()
+            .iter()
+            .filter(|stack| stack.owner == connection.identity())
+            .collect();
+        stacks.sort_by_key(|stack| stack.inventory_id);
+
+        Ok(stacks)
+    }
+
+    pub fn get_item_definition(
+        &self,
+        item_id: u32,
+    ) -> Result<Option<ItemDefinition>, RustLibError> {
+        let connection = self.connection_module.get_connection()?;
+
+        Ok(connection.db().item_definition().item_id().find(&item_id))
+    }
+
+    /// Effects of every artifact the local player has equipped.
+    pub fn get_equipped_effects(&self) -> Result<Vec<ItemEffect>, RustLibError> {
+        let mut effects = Vec::new();
+        for stack in self.get_local_inventory()? {
+            if !stack.equipped {
+                continue;
+            }
+
+            if let Some(item) = self.get_item_definition(stack.item_id)? {
+                effects.extend(item.effects);
+            }
+        }
+
+        Ok(effects)
+    }
+
+    pub fn is_chest_opened(&self, chest_id: u64) -> Result<bool, RustLibError> {
+        let connection = self.connection_module.get_connection()?;
+
+        Ok(connection.db().chest_opening().iter().any(|opening| {
+            opening.chest_id == chest_id && opening.opened_by == connection.identity()
+        }))
+    }
+
+    /// Whether the local player already completed their current scene.
+    pub fn is_scene_completed(&self) -> Result<bool, RustLibError> {
+        let connection = self.connection_module.get_connection()?;
+        let scene_id = self.get_current_scene_id()?;
+
+        Ok(connection.db().scene_completion().iter().any(|completion| {
+            completion.identity == connection.identity() && completion.scene_id == scene_id
+        }))
+    }
 }
 
 impl SpacetimeDBManager {
-    pub fn send_inputs(&self, state: DbPlayerState) -> Result<(), RustLibError> {
+    /// Sends the local player's state as the next input and returns its sequence.
+    pub fn send_inputs(&self, mut state: DbPlayerState) -> Result<u32, RustLibError> {
         self.login_module.require_logged_in()?;
 
         let connection = self.connection_module.get_connection()?;
+
+        state.input_sequence = self.input_sequence.fetch_add(1, Ordering::Relaxed) + 1;
+        let sequence = state.input_sequence;
+
         match connection.reducers.send_player_state(state) {
-            Ok(_) => Ok(()),
+            Ok(_) => Ok(sequence),
             Err(e) => {
                 godot_print!("Failed to update position: {}", e);
 
@@ -222,22 +444,165 @@ impl SpacetimeDBManager {
         }
     }
 
-    pub fn collect_coin_at_position(&self, position: Vector2) -> Result<(), RustLibError> {
+    pub fn collect_coin(&self, coin_id: u64) -> Result<(), RustLibError> {
         self.login_module.require_logged_in()?;
 
         let connection = self.connection_module.get_connection()?;
-        let db_position = DbVector2 {
-            x: position.x,
-            y: position.y,
-        };
+        match connection.reducers.try_collect_coin(coin_id) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                godot_print!("Failed to collect coin {}: {}", coin_id, e);
+
+                Err(RustLibError::SpacetimeSDK { source: e })
+            }
+        }
+    }
+
+    pub fn stomp_enemy(&self, enemy_id: u64) -> Result<(), RustLibError> {
+        self.login_module.require_logged_in()?;
+
+        let connection = self.connection_module.get_connection()?;
+        match connection.reducers.stomp_enemy(enemy_id) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                godot_print!("Failed to stomp enemy {}: {}", enemy_id, e);
+
+                Err(RustLibError::SpacetimeSDK { source: e })
+            }
+        }
+    }
+
+    pub fn activate_checkpoint(&self, checkpoint_id: u64) -> Result<(), RustLibError> {
+        self.login_module.require_logged_in()?;
+
+        let connection = self.connection_module.get_connection()?;
+        match connection.reducers.activate_checkpoint(checkpoint_id) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                godot_print!("Failed to activate checkpoint {}: {}", checkpoint_id, e);
+
+                Err(RustLibError::SpacetimeSDK { source: e })
+            }
+        }
+    }
+
+    pub fn open_chest(&self, chest_id: u64) -> Result<(), RustLibError> {
+        self.login_module.require_logged_in()?;
+
+        let connection = self.connection_module.get_connection()?;
+        match connection.reducers.open_chest(chest_id) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                godot_print!("Failed to open chest {}: {}", chest_id, e);
+
+                Err(RustLibError::SpacetimeSDK { source: e })
+            }
+        }
+    }
+
+    pub fn complete_scene(&self) -> Result<(), RustLibError> {
+        self.login_module.require_logged_in()?;
 
-        match connection.reducers.try_collect_coin(db_position) {
+        let connection = self.connection_module.get_connection()?;
+        match connection.reducers.complete_scene() {
             Ok(_) => Ok(()),
+            Err(e) => {
+                godot_print!("Failed to complete scene: {}", e);
+
+                Err(RustLibError::SpacetimeSDK { source: e })
+            }
+        }
+    }
+
+    pub fn drop_item(&self, inventory_id: u64, quantity: u32) -> Result<(), RustLibError> {
+        self.login_module.require_logged_in()?;
+
+        let connection = self.connection_module.get_connection()?;
+        match connection.reducers.drop_item(inventory_id, quantity) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                godot_print!("Failed to drop from stack {}: {}", inventory_id, e);
+
+                Err(RustLibError::SpacetimeSDK { source: e })
+            }
+        }
+    }
+
+    pub fn pick_up_item(&self, drop_id: u64) -> Result<(), RustLibError> {
+        self.login_module.require_logged_in()?;
+
+        let connection = self.connection_module.get_connection()?;
+        match connection.reducers.pick_up_item(drop_id) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                godot_print!("Failed to pick up item {}: {}", drop_id, e);
+
+                Err(RustLibError::SpacetimeSDK { source: e })
+            }
+        }
+    }
+
+    pub fn equip_item(&self, inventory_id: u64) -> Result<(), RustLibError> {
+        self.login_module.require_logged_in()?;
+
+        let connection = self.connection_module.get_connection()?;
+        match connection.reducers.equip_item(inventory_id) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                godot_print!("Failed to equip stack {}: {}", inventory_id, e);
+
+                Err(RustLibError::SpacetimeSDK { source: e })
+            }
+        }
+    }
+
+    pub fn unequip_item(&self, inventory_id: u64) -> Result<(), RustLibError> {
+        self.login_module.require_logged_in()?;
+
+        let connection = self.connection_module.get_connection()?;
+        match connection.reducers.unequip_item(inventory_id) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                godot_print!("Failed to unequip stack {}: {}", inventory_id, e);
+
+                Err(RustLibError::SpacetimeSDK { source: e })
+            }
+        }
+    }
+
+    pub fn report_hazard(&self, hazard: Hazard) -> Result<(), RustLibError> {
+        self.login_module.require_logged_in()?;
+
+        let connection = self.connection_module.get_connection()?;
+        match connection.reducers.report_hazard(hazard.clone()) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                godot_print!("Failed to report hazard {:?}: {}", hazard, e);
+
+                Err(RustLibError::SpacetimeSDK { source: e })
+            }
+        }
+    }
+
+    pub fn change_scene(&self, portal_name: &str) -> Result<(), RustLibError> {
+        self.login_module.require_logged_in()?;
+
+        if Self::is_scene_transition_pending() {
+            return Ok(());
+        }
+
+        let connection = self.connection_module.get_connection()?;
+        match connection.reducers.change_scene(portal_name.to_string()) {
+            Ok(_) => {
+                *SCENE_TRANSITION_STATE.lock().unwrap() =
+                    SceneTransitionState::Requested(portal_name.to_string());
+
+                Ok(())
+            }
             Err(e) => {
                 godot_print!(
-                    "Failed to collect coin at ({}, {}): {}",
-                    position.x,
-                    position.y,
+                    "Failed to change scene through portal {}: {}",
+                    portal_name,
                     e
                 );
 
@@ -245,4 +610,12 @@ impl SpacetimeDBManager {
             }
         }
     }
+
+    pub fn is_scene_transition_pending() -> bool {
+        *SCENE_TRANSITION_STATE.lock().unwrap() != SceneTransitionState::Idle
+    }
+
+    pub fn finish_scene_transition() {
+        *SCENE_TRANSITION_STATE.lock().unwrap() = SceneTransitionState::Idle;
+    }
 }
@@ -1,25 +1,54 @@
-use spacetimedb_sdk::Table;
+use crate::{
+    ClockSync, DbConnection, RustLibError, SpacetimeDBManager, WorldClock, WorldClockTableAccess,
+    WorldScene, WorldSceneTableAccess,
+};
 
-use crate::{DbConnection, RustLibError, WorldScene, WorldSceneTableAccess};
-
-pub fn get_diff_between_timestamps(world_scene: &WorldScene) -> i64 {
-    let creation_time = world_scene
-        .creation_time
+/// Microseconds of shared world time elapsed since the scene clock started.
+pub fn get_diff_between_timestamps(world_clock: &WorldClock) -> i64 {
+    let creation_time = world_clock
+        .started_at
         .to_time_duration_since_unix_epoch()
         .to_micros();
-    let last_update_time = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_micros() as i64;
 
-    last_update_time - creation_time
+    ClockSync::server_now_micros() - creation_time
+}
+
+/// Shared world time of the scene the local player is in, if it is known yet.
+pub fn get_current_world_time() -> Option<i64> {
+    let db_manager = SpacetimeDBManager::get_read_connection()?;
+    let connection = db_manager.get_connection().ok()?;
+    let scene_id = db_manager.get_current_scene_id().ok()?;
+    let world_clock = get_world_clock(connection, scene_id).ok()?;
+
+    Some(get_diff_between_timestamps(&world_clock))
 }
 
-pub fn get_world_scene(connection: &DbConnection) -> Result<WorldScene, RustLibError> {
+pub fn get_world_scene(
+    connection: &DbConnection,
+    scene_id: u32,
+) -> Result<WorldScene, RustLibError> {
     connection
         .db
         .world_scene()
-        .iter()
-        .find(|x| x.scene_id == 1)
-        .ok_or(RustLibError::WorldSetup("No platforms found".to_string()))
+        .scene_id()
+        .find(&scene_id)
+        .ok_or(RustLibError::WorldSetup(format!(
+            "World scene {} not found",
+            scene_id
+        )))
+}
+
+pub fn get_world_clock(
+    connection: &DbConnection,
+    scene_id: u32,
+) -> Result<WorldClock, RustLibError> {
+    connection
+        .db
+        .world_clock()
+        .scene_id()
+        .find(&scene_id)
+        .ok_or(RustLibError::WorldSetup(format!(
+            "World clock for scene {} not found",
+            scene_id
+        )))
 }
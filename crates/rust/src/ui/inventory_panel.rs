@@ -0,0 +1,314 @@
+use crate::{
+    DbConnection, InventoryItem, ItemDefinition, ItemEffect, ItemRarity, SpacetimeDBManager,
+    drop_item, equip_item, unequip_item,
+};
+
+use godot::classes::{Button, IPanelContainer, Input, ItemList, Label, PanelContainer};
+use godot::prelude::*;
+
+use spacetimedb_sdk::DbContext;
+
+/// Lists the local player's inventory, toggled with the `inventory` action.
+/// The list is rebuilt whenever the subscribed `inventory` rows change.
+#[derive(GodotClass)]
+#[class(base=PanelContainer)]
+pub struct InventoryPanel {
+    /// Stacks shown in the list, in list order.
+    stacks: Vec<InventoryItem>,
+
+    item_list: Option<Gd<ItemList>>,
+    details_label: Option<Gd<Label>>,
+    equip_button: Option<Gd<Button>>,
+    drop_button: Option<Gd<Button>>,
+
+    #[base]
+    base: Base<PanelContainer>,
+}
+
+#[godot_api]
+impl IPanelContainer for InventoryPanel {
+    fn init(base: Base<PanelContainer>) -> Self {
+        Self {
+            stacks: Vec::new(),
+            item_list: None,
+            details_label: None,
+            equip_button: None,
+            drop_button: None,
+            base,
+        }
+    }
+
+    fn ready(&mut self) {
+        self.setup_node_references();
+        self.connect_signals();
+        self.base_mut().set_visible(false);
+    }
+
+    fn process(&mut self, _delta: f64) {
+        if Input::singleton().is_action_just_pressed("inventory") {
+            let visible = !self.base().is_visible();
+            self.base_mut().set_visible(visible);
+        }
+
+        if self.base().is_visible() {
+            self.refresh_items();
+        }
+    }
+}
+
+#[godot_api]
+impl InventoryPanel {
+    fn setup_node_references(&mut self) {
+        self.item_list = self.base().try_get_node_as::<ItemList>("Content/Items");
+        self.details_label = self.base().try_get_node_as::<Label>("Content/Details");
+        self.equip_button = self
+            .base()
+            .try_get_node_as::<Button>("Content/Actions/EquipButton");
+        self.drop_button = self
+            .base()
+            .try_get_node_as::<Button>("Content/Actions/DropButton");
+
+        if self.item_list.is_none() {
+            godot_error!("Could not find Content/Items node");
+        }
+        if self.equip_button.is_none() || self.drop_button.is_none() {
+            godot_error!("Could not find inventory action buttons");
+        }
+    }
+
+    fn connect_signals(&mut self) {
+        let callback = self.base().callable("on_item_selected");
+        if let Some(item_list) = &mut self.item_list {
+            item_list.connect("item_selected", &callback);
+        }
+
+        let callback = self.base().callable("on_equip_pressed");
+        if let Some(equip_button) = &mut self.equip_button {
+            equip_button.connect("pressed", &callback);
+        }
+
+        let callback = self.base().callable("on_drop_pressed");
+        if let Some(drop_button) = &mut self.drop_button {
+            drop_button.connect("pressed", &callback);
+        }
+    }
+
+    #[func]
+    fn on_item_selected(&mut self, _index: i64) {
+        self.update_selection();
+    }
+
+    #[func]
+    fn on_equip_pressed(&mut self) {
+        let Some(stack) = self.selected_stack() else {
+            return;
+        };
+
+        let Some(connection) = SpacetimeDBManager::get_read_connection() else {
+            godot_error!("Could not get database connection!");
+            return;
+        };
+
+        let result = if stack.equipped {
+            connection.unequip_item(stack.inventory_id)
+        } else {
+            connection.equip_item(stack.inventory_id)
+        };
+
+        if let Err(e) = result {
+            godot_error!("Failed to change stack {}: {}", stack.inventory_id, e);
+        }
+    }
+
+    /// Drops a single unit of the selected stack.
+    #[func]
+    fn on_drop_pressed(&mut self) {
+        let Some(stack) = self.selected_stack() else {
+            return;
+        };
+
+        let Some(connection) = SpacetimeDBManager::get_read_connection() else {
+            godot_error!("Could not get database connection!");
+            return;
+        };
+
+        if let Err(e) = connection.drop_item(stack.inventory_id, 1) {
+            godot_error!("Failed to drop from stack {}: {}", stack.inventory_id, e);
+        }
+    }
+}
+
+impl InventoryPanel {
+    pub fn setup_multiplayer(connection: &DbConnection) {
+        connection
+            .subscription_builder()
+            .subscribe(["SELECT * FROM item_definition", "SELECT * FROM inventory"]);
+
+        connection.reducers.on_equip_item(|ctx, inventory_id| {
+            if ctx.event.caller_identity != ctx.identity() {
+                return;
+            }
+
+            if let spacetimedb_sdk::Status::Failed(e) = &ctx.event.status {
+                godot_print!("Equipping stack {} rejected: {}", inventory_id, e);
+            }
+        });
+
+        connection.reducers.on_unequip_item(|ctx, inventory_id| {
+            if ctx.event.caller_identity != ctx.identity() {
+                return;
+            }
+
+            if let spacetimedb_sdk::Status::Failed(e) = &ctx.event.status {
+                godot_print!("Unequipping stack {} rejected: {}", inventory_id, e);
+            }
+        });
+
+        connection
+            .reducers
+            .on_drop_item(|ctx, inventory_id, quantity| {
+                if ctx.event.caller_identity != ctx.identity() {
+                    return;
+                }
+
+                if let spacetimedb_sdk::Status::Failed(e) = &ctx.event.status {
+                    godot_print!(
+                        "Dropping {} from stack {} rejected: {}",
+                        quantity,
+                        inventory_id,
+                        e
+                    );
+                }
+            });
+    }
+
+    fn refresh_items(&mut self) {
+        let (stacks, definitions) = {
+            let Some(db_manager) = SpacetimeDBManager::get_read_connection() else {
+                return;
+            };
+
+            let Ok(stacks) = db_manager.get_local_inventory() else {
+                return;
+            };
+
+            if stacks == self.stacks {
+                return;
+            }
+
+            let definitions: Vec<Option<ItemDefinition>> = stacks
+                .iter()
+                .map(|stack| db_manager.get_item_definition(stack.item_id).ok().flatten())
+                .collect();
+
+            (stacks, definitions)
+        };
+
+        let Some(item_list) = &mut self.item_list else {
+            return;
+        };
+
+        let selected_id = Self::selected_index(item_list)
+            .and_then(|index| self.stacks.get(index))
+            .map(|stack| stack.inventory_id);
+
+        item_list.clear();
+        for (stack, definition) in stacks.iter().zip(&definitions) {
+            let name = definition
+                .as_ref()
+                .map_or("Unknown item".to_string(), |item| item.name.clone());
+
+            let mut text = name;
+            if stack.quantity > 1 {
+                text.push_str(&format!(" x{}", stack.quantity));
+            }
+            if stack.equipped {
+                text.push_str(" (equipped)");
+            }
+
+            let index = item_list.add_item(&text);
+            if let Some(item) = definition {
+                item_list.set_item_custom_fg_color(index, rarity_color(item.rarity));
+            }
+
+            if Some(stack.inventory_id) == selected_id {
+                item_list.select(index);
+            }
+        }
+
+        self.stacks = stacks;
+        self.update_selection();
+    }
+
+    fn update_selection(&mut self) {
+        let stack = self.selected_stack();
+
+        let details = match &stack {
+            Some(stack) => {
+                let definition = SpacetimeDBManager::get_read_connection()
+                    .and_then(|db_manager| db_manager.get_item_definition(stack.item_id).ok())
+                    .flatten();
+
+                definition.map_or(String::new(), |item| describe(&item))
+            }
+            None => String::new(),
+        };
+
+        if let Some(label) = &mut self.details_label {
+            label.set_text(&details);
+        }
+
+        if let Some(button) = &mut self.equip_button {
+            button.set_disabled(stack.is_none());
+            button.set_text(if stack.as_ref().is_some_and(|stack| stack.equipped) {
+                "Unequip"
+            } else {
+                "Equip"
+            });
+        }
+
+        if let Some(button) = &mut self.drop_button {
+            button.set_disabled(stack.is_none());
+        }
+    }
+
+    fn selected_stack(&self) -> Option<InventoryItem> {
+        let mut item_list = self.item_list.clone()?;
+        let index = Self::selected_index(&mut item_list)?;
+        self.stacks.get(index).cloned()
+    }
+
+    fn selected_index(item_list: &mut Gd<ItemList>) -> Option<usize> {
+        item_list
+            .get_selected_items()
+            .as_slice()
+            .first()
+            .map(|index| *index as usize)
+    }
+}
+
+fn describe(item: &ItemDefinition) -> String {
+    let mut lines = vec![format!("{} ({:?})", item.name, item.rarity)];
+    if !item.description.is_empty() {
+        lines.push(item.description.clone());
+    }
+
+    for effect in &item.effects {
+        lines.push(match effect {
+            ItemEffect::SpeedBonus(bonus) => format!("+{:.0}% speed", bonus * 100.0),
+            ItemEffect::JumpBonus(bonus) => format!("+{:.0}% jump", bonus * 100.0),
+        });
+    }
+
+    lines.join("\n")
+}
+
+fn rarity_color(rarity: ItemRarity) -> Color {
+    match rarity {
+        ItemRarity::Common => Color::from_rgb(0.9, 0.9, 0.9),
+        ItemRarity::Uncommon => Color::from_rgb(0.3, 0.85, 0.3),
+        ItemRarity::Rare => Color::from_rgb(0.3, 0.55, 1.0),
+        ItemRarity::Epic => Color::from_rgb(0.7, 0.35, 0.95),
+        ItemRarity::Legendary => Color::from_rgb(1.0, 0.6, 0.1),
+    }
+}
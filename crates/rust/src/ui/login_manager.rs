@@ -1,8 +1,11 @@
-use crate::{ConnectionState, LevelManager, SpacetimeDBManager};
+use crate::{ConnectionState, LevelManager, SpacetimeDBManager, WorldSceneTableAccess};
 
+use game_rules::names::{NameError, validate_player_name};
 use godot::classes::{Button, IVBoxContainer, Label, LineEdit, VBoxContainer};
 use godot::prelude::*;
 
+use spacetimedb_sdk::DbContext;
+
 #[derive(Clone, PartialEq, Debug, Default, PartialOrd)]
 pub enum LoginUIState {
     #[default]
@@ -137,10 +140,17 @@ impl LoginScreen {
 
         let username = username_input.get_text().to_string();
 
-        if username.trim().is_empty() {
-            self.update_status_with_failed_state("Please enter a username");
-            return;
-        }
+        let username = match validate_player_name(&username) {
+            Ok(username) => username.to_string(),
+            Err(NameError::Empty) => {
+                self.update_status_with_failed_state("Please enter a username");
+                return;
+            }
+            Err(e) => {
+                self.update_status_with_failed_state(&e.to_string());
+                return;
+            }
+        };
 
         self.ui_state = LoginUIState::LoginAttempted;
 
@@ -161,7 +171,8 @@ impl LoginScreen {
             }
         }
 
-        match connection.register_player(username, 1) {
+        let scene_id = self.level_manager.get_entry_scene_id();
+        match connection.register_player(username, scene_id) {
             Ok(_) => {
                 self.set_status("Registration request sent...");
             }
@@ -197,9 +208,28 @@ impl LoginScreen {
     }
 
     fn transition_to_game(&mut self) {
+        let scene_path = self
+            .get_current_scene_name()
+            .and_then(|name| self.level_manager.get_scene_path(&name))
+            .unwrap_or_else(|| self.level_manager.get_entry_scene_path());
+
         if let Some(mut scene_tree) = self.base().get_tree() {
-            let error = scene_tree.change_scene_to_file(&self.level_manager.get_entry_scene_path());
+            let error = scene_tree.change_scene_to_file(&scene_path);
             godot_print!("Transitioned to game. State: {:?}", error);
         }
     }
+
+    fn get_current_scene_name(&self) -> Option<String> {
+        let connection = SpacetimeDBManager::get_read_connection()?;
+        let scene_id = connection.get_current_scene_id().ok()?;
+
+        connection
+            .get_connection()
+            .ok()?
+            .db()
+            .world_scene()
+            .scene_id()
+            .find(&scene_id)
+            .map(|scene| scene.name)
+    }
 }
@@ -1,3 +1,5 @@
+mod inventory_panel;
 mod login_manager;
 
+pub use inventory_panel::*;
 pub use login_manager::*;
@@ -0,0 +1,38 @@
+use crate::{DbVector2, MovementPath};
+
+use game_rules::kinematics;
+
+/// Client side of the server's `MovementPath` in
+/// `crates/server/src/elements/kinematics.rs`; both evaluate paths with
+/// `game_rules::kinematics`, so platforms sit where the server thinks they are.
+impl MovementPath {
+    pub fn is_valid(&self) -> bool {
+        kinematics::is_valid_path(self.waypoints.len(), self.period, self.easing)
+    }
+
+    /// Position at `world_seconds` into the scene clock.
+    pub fn position_at(&self, world_seconds: f64) -> DbVector2 {
+        let segment = kinematics::path_segment(
+            self.waypoints.len(),
+            self.period,
+            self.easing,
+            world_seconds,
+        );
+
+        let Some((segment, weight)) = segment else {
+            return self
+                .waypoints
+                .first()
+                .cloned()
+                .unwrap_or(DbVector2 { x: 0.0, y: 0.0 });
+        };
+
+        let from = &self.waypoints[segment];
+        let to = &self.waypoints[segment + 1];
+
+        DbVector2 {
+            x: kinematics::lerp(from.x, to.x, weight),
+            y: kinematics::lerp(from.y, to.y, weight),
+        }
+    }
+}
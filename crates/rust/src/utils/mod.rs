@@ -1 +1,2 @@
 pub mod converter;
+pub mod kinematics;
@@ -0,0 +1,56 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("usage: scene-export <export|check> <scene.tscn> <layout.ron>")]
+    Usage,
+
+    #[error("failed to access {path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("line {line}: malformed node header: {header}")]
+    MalformedHeader { line: usize, header: String },
+
+    #[error("node {0} has no position")]
+    MissingPosition(String),
+
+    #[error("node {path} has an invalid {key}: {value}")]
+    InvalidVector {
+        path: String,
+        key: String,
+        value: String,
+    },
+
+    #[error("portal {0} has no rectangular CollisionShape2D child")]
+    MissingPortalShape(String),
+
+    #[error("portal {path} has no {key} metadata")]
+    MissingPortalMetadata { path: String, key: String },
+
+    #[error("node {path} has an unsupported move animation: {reason}")]
+    UnsupportedAnimation { path: String, reason: String },
+
+    #[error("node {path} is at {position} but its move animation starts at {start}")]
+    PathStartMismatch {
+        path: String,
+        position: String,
+        start: String,
+    },
+
+    #[error("kill zone {0} needs an upward-facing WorldBoundaryShape2D child")]
+    UnsupportedKillZone(String),
+
+    #[error("enemy {0} does not instance a character scene")]
+    UnknownEnemyKind(String),
+
+    #[error("chest {0} has no metadata/loot_table")]
+    MissingLootTable(String),
+
+    #[error("scene has no {0} node")]
+    MissingNode(String),
+
+    #[error("{layout} is out of date with {scene}, run `cargo make scene-export`")]
+    OutOfDate { layout: String, scene: String },
+}
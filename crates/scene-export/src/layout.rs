@@ -0,0 +1,490 @@
+use crate::errors::ExportError;
+use crate::path::MovementPath;
+use crate::tscn::{TscnNode, TscnScene, parse_vector2};
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+const SPAWN_POINT: &str = "SpawnPoint";
+const COINS: &str = "Coins";
+const PLATFORMS: &str = "Platforms";
+const ENEMIES: &str = "Enemies";
+const PORTALS: &str = "Portals";
+const CHECKPOINTS: &str = "Checkpoints";
+const KILL_ZONE: &str = "Killzone";
+const CHESTS: &str = "Chests";
+const GOAL: &str = "Goal";
+const TARGET_SCENE: &str = "metadata/target_scene";
+const TARGET_SPAWN: &str = "metadata/target_spawn";
+/// Node metadata naming a loot table from `crates/server/items/loot.ron`.
+const LOOT_TABLE: &str = "metadata/loot_table";
+
+/// Placements the server needs from a Godot scene. Mirrors
+/// `SceneLayout` in `crates/server/src/world_state/scene_definition.rs`.
+pub struct SceneLayout {
+    spawn_point: (f32, f32),
+    coins: Vec<(f32, f32)>,
+    platforms: Vec<PlatformPlacement>,
+    enemies: Vec<EnemyPlacement>,
+    portals: Vec<PortalLayout>,
+    checkpoints: Vec<CheckpointPlacement>,
+    chests: Vec<ChestPlacement>,
+    goal: Option<(f32, f32)>,
+    /// Height of the kill zone's boundary; players below it have fallen out.
+    kill_zone: Option<f32>,
+}
+
+/// A portal node under `Portals`: its trigger comes from the rectangular
+/// collision shape child, its destination from the node's metadata.
+struct PortalLayout {
+    name: String,
+    trigger_position: (f32, f32),
+    trigger_size: (f32, f32),
+    target_scene: String,
+    target_spawn: (f32, f32),
+}
+
+struct PlatformPlacement {
+    name: String,
+    position: (f32, f32),
+    path: Option<MovementPath>,
+}
+
+struct EnemyPlacement {
+    name: String,
+    /// `EnemyKind` variant, named after the instanced character scene.
+    kind: String,
+    position: (f32, f32),
+    path: Option<MovementPath>,
+}
+
+struct CheckpointPlacement {
+    name: String,
+    position: (f32, f32),
+}
+
+struct ChestPlacement {
+    name: String,
+    position: (f32, f32),
+    loot_table: String,
+}
+
+impl SceneLayout {
+    pub fn from_scene(scene: &TscnScene) -> Result<Self, ExportError> {
+        let nodes = scene.nodes.as_slice();
+
+        let mut local_positions = HashMap::new();
+        for node in nodes {
+            if let Some(position) = node.position()? {
+                local_positions.insert(node.path(), position);
+            }
+        }
+
+        // Positions in the file are relative to the parent, so every ancestor
+        // inside this scene contributes its offset.
+        let offset = |path: &str| {
+            let mut prefix = String::new();
+            let mut position = (0.0, 0.0);
+            for segment in path.split('/') {
+                if !prefix.is_empty() {
+                    prefix.push('/');
+                }
+                prefix.push_str(segment);
+
+                if let Some((x, y)) = local_positions.get(&prefix) {
+                    position.0 += x;
+                    position.1 += y;
+                }
+            }
+            position
+        };
+
+        let global_position = |node: &TscnNode| -> Result<(f32, f32), ExportError> {
+            let path = node.path();
+            if !local_positions.contains_key(&path) {
+                return Err(ExportError::MissingPosition(path));
+            }
+
+            Ok(offset(&path))
+        };
+
+        let portal = |node: &TscnNode| -> Result<PortalLayout, ExportError> {
+            let path = node.path();
+            let missing_metadata = |key: &str| ExportError::MissingPortalMetadata {
+                path: path.clone(),
+                key: key.to_string(),
+            };
+
+            // The node's own position is required; the shape may sit at the
+            // portal's origin.
+            global_position(node)?;
+            let (shape_path, trigger_size) = nodes
+                .iter()
+                .filter(|child| child.parent.as_deref() == Some(path.as_str()))
+                .find_map(|child| {
+                    let shape = scene.sub_resource(child.properties.get("shape")?)?;
+                    Some((child.path(), parse_vector2(shape.properties.get("size")?)?))
+                })
+                .ok_or_else(|| ExportError::MissingPortalShape(path.clone()))?;
+
+            Ok(PortalLayout {
+                name: node.name.clone(),
+                trigger_position: offset(&shape_path),
+                trigger_size,
+                target_scene: node
+                    .string(TARGET_SCENE)
+                    .ok_or_else(|| missing_metadata(TARGET_SCENE))?,
+                target_spawn: node
+                    .vector(TARGET_SPAWN)?
+                    .ok_or_else(|| missing_metadata(TARGET_SPAWN))?,
+            })
+        };
+
+        // Track values are relative to the node's parent. Playing the
+        // animation moves the node to its first key, so a node placed
+        // elsewhere would jump on the first frame.
+        let moving_placement = |node: &TscnNode| {
+            let position = global_position(node)?;
+            let (local_x, local_y) = node.position()?.unwrap_or_default();
+            let parent_offset = (position.0 - local_x, position.1 - local_y);
+
+            let path = MovementPath::from_animation(scene, &node.path(), parent_offset)?;
+            if let Some(start) = path.as_ref().map(|path| path.waypoints[0])
+                && format_vector(start) != format_vector(position)
+            {
+                return Err(ExportError::PathStartMismatch {
+                    path: node.path(),
+                    position: format_vector(position),
+                    start: format_vector(start),
+                });
+            }
+
+            Ok((position, path))
+        };
+
+        // Only an upward-facing `WorldBoundaryShape2D` is supported, which is
+        // what the kill zone scene is set up with.
+        let kill_zone = |node: &TscnNode| -> Result<f32, ExportError> {
+            let path = node.path();
+            let unsupported = || ExportError::UnsupportedKillZone(path.clone());
+
+            let (shape_path, shape) = nodes
+                .iter()
+                .filter(|child| child.parent.as_deref() == Some(path.as_str()))
+                .find_map(|child| {
+                    Some((
+                        child.path(),
+                        scene.sub_resource(child.properties.get("shape")?)?,
+                    ))
+                })
+                .ok_or_else(unsupported)?;
+            if shape.resource_type != "WorldBoundaryShape2D" {
+                return Err(unsupported());
+            }
+            if let Some(normal) = shape.properties.get("normal")
+                && parse_vector2(normal) != Some((0.0, -1.0))
+            {
+                return Err(unsupported());
+            }
+
+            let distance = match shape.properties.get("distance") {
+                Some(distance) => distance.parse::<f32>().map_err(|_| unsupported())?,
+                None => 0.0,
+            };
+
+            Ok(offset(&shape_path).1 - distance)
+        };
+
+        let children_of = |parent: &'static str| {
+            nodes
+                .iter()
+                .filter(move |node| node.parent.as_deref() == Some(parent))
+        };
+
+        let spawn_node = nodes
+            .iter()
+            .find(|node| node.path() == SPAWN_POINT)
+            .ok_or(ExportError::MissingNode(SPAWN_POINT.to_string()))?;
+
+        Ok(Self {
+            spawn_point: global_position(spawn_node)?,
+            coins: children_of(COINS)
+                .map(&global_position)
+                .collect::<Result<_, _>>()?,
+            platforms: children_of(PLATFORMS)
+                .map(|node| {
+                    let (position, path) = moving_placement(node)?;
+
+                    Ok(PlatformPlacement {
+                        name: node.name.clone(),
+                        position,
+                        path,
+                    })
+                })
+                .collect::<Result<_, ExportError>>()?,
+            enemies: children_of(ENEMIES)
+                .map(|node| {
+                    let (position, path) = moving_placement(node)?;
+
+                    Ok(EnemyPlacement {
+                        name: node.name.clone(),
+                        kind: enemy_kind(scene, node)?,
+                        position,
+                        path,
+                    })
+                })
+                .collect::<Result<_, ExportError>>()?,
+            portals: children_of(PORTALS).map(portal).collect::<Result<_, _>>()?,
+            checkpoints: children_of(CHECKPOINTS)
+                .map(|node| {
+                    Ok(CheckpointPlacement {
+                        name: node.name.clone(),
+                        position: global_position(node)?,
+                    })
+                })
+                .collect::<Result<_, ExportError>>()?,
+            chests: children_of(CHESTS)
+                .map(|node| {
+                    Ok(ChestPlacement {
+                        name: node.name.clone(),
+                        position: global_position(node)?,
+                        loot_table: loot_table(node)?,
+                    })
+                })
+                .collect::<Result<_, ExportError>>()?,
+            goal: nodes
+                .iter()
+                .find(|node| node.path() == GOAL)
+                .map(&global_position)
+                .transpose()?,
+            kill_zone: scene.node(KILL_ZONE).map(kill_zone).transpose()?,
+        })
+    }
+
+    pub fn to_ron(&self, scene_file: &str) -> String {
+        let mut out = String::new();
+
+        // Writing into a String cannot fail.
+        let _ = writeln!(
+            out,
+            "// Generated by scene-export from {scene_file}; do not edit by hand."
+        );
+        out.push_str("SceneLayout(\n");
+        let _ = writeln!(out, "    spawn_point: {},", format_vector(self.spawn_point));
+
+        out.push_str("    coins: [\n");
+        for position in &self.coins {
+            let _ = writeln!(out, "        (position: {}),", format_vector(*position));
+        }
+        out.push_str("    ],\n");
+
+        out.push_str("    platforms: [\n");
+        for platform in &self.platforms {
+            let _ = write!(
+                out,
+                "        (name: {:?}, position: {}",
+                platform.name,
+                format_vector(platform.position)
+            );
+            Self::write_path(&mut out, platform.path.as_ref());
+            out.push_str("),\n");
+        }
+        out.push_str("    ],\n");
+
+        out.push_str("    enemies: [\n");
+        for enemy in &self.enemies {
+            let _ = write!(
+                out,
+                "        (name: {:?}, kind: {}, position: {}",
+                enemy.name,
+                enemy.kind,
+                format_vector(enemy.position)
+            );
+            Self::write_path(&mut out, enemy.path.as_ref());
+            out.push_str("),\n");
+        }
+        out.push_str("    ],\n");
+
+        out.push_str("    portals: [\n");
+        for portal in &self.portals {
+            out.push_str("        (\n");
+            let _ = writeln!(out, "            name: {:?},", portal.name);
+            let _ = writeln!(
+                out,
+                "            trigger_position: {},",
+                format_vector(portal.trigger_position)
+            );
+            let _ = writeln!(
+                out,
+                "            trigger_size: {},",
+                format_vector(portal.trigger_size)
+            );
+            let _ = writeln!(out, "            target_scene: {:?},", portal.target_scene);
+            let _ = writeln!(
+                out,
+                "            target_spawn: {},",
+                format_vector(portal.target_spawn)
+            );
+            out.push_str("        ),\n");
+        }
+        out.push_str("    ],\n");
+
+        out.push_str("    checkpoints: [\n");
+        for checkpoint in &self.checkpoints {
+            let _ = writeln!(
+                out,
+                "        (name: {:?}, position: {}),",
+                checkpoint.name,
+                format_vector(checkpoint.position)
+            );
+        }
+        out.push_str("    ],\n");
+
+        out.push_str("    chests: [\n");
+        for chest in &self.chests {
+            let _ = writeln!(
+                out,
+                "        (name: {:?}, position: {}, loot_table: {:?}),",
+                chest.name,
+                format_vector(chest.position),
+                chest.loot_table
+            );
+        }
+        out.push_str("    ],\n");
+
+        if let Some(goal) = self.goal {
+            let _ = writeln!(out, "    goal: Some({}),", format_vector(goal));
+        }
+
+        if let Some(kill_zone) = self.kill_zone {
+            let _ = writeln!(out, "    kill_zone: Some({:?}),", normalize(kill_zone));
+        }
+
+        out.push_str(")\n");
+        out
+    }
+
+    fn write_path(out: &mut String, path: Option<&MovementPath>) {
+        let Some(path) = path else {
+            return;
+        };
+
+        let waypoints = path
+            .waypoints
+            .iter()
+            .map(|waypoint| format_vector(*waypoint))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let _ = write!(
+            out,
+            ", path: Some((waypoints: [{waypoints}], period: {:?}, easing: {:?}))",
+            path.period, path.easing
+        );
+    }
+}
+
+/// `res://scenes/characters/green_slime.tscn` becomes `GreenSlime`.
+fn enemy_kind(scene: &TscnScene, node: &TscnNode) -> Result<String, ExportError> {
+    let stem = scene
+        .instanced_scene(node)
+        .and_then(|path| path.rsplit('/').next())
+        .and_then(|file| file.strip_suffix(".tscn"))
+        .ok_or_else(|| ExportError::UnknownEnemyKind(node.path()))?;
+
+    Ok(stem
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect())
+}
+
+fn loot_table(node: &TscnNode) -> Result<String, ExportError> {
+    node.properties
+        .get(LOOT_TABLE)
+        .map(|value| value.trim_matches('"').to_string())
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| ExportError::MissingLootTable(node.path()))
+}
+
+fn format_vector((x, y): (f32, f32)) -> String {
+    format!("(x: {:?}, y: {:?})", normalize(x), normalize(y))
+}
+
+/// Godot saves values that were zeroed in the editor as float noise such as
+/// `2.08165e-12`; rounding to a thousandth keeps that out of the layouts.
+fn normalize(value: f32) -> f32 {
+    let rounded = (value * 1000.0).round() / 1000.0;
+    // Also folds -0.0 into 0.0.
+    if rounded == 0.0 { 0.0 } else { rounded }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tscn;
+
+    #[test]
+    fn fixture_layout_is_exported() {
+        let scene = tscn::parse(include_str!("../fixtures/level.tscn")).unwrap();
+        let layout = SceneLayout::from_scene(&scene).unwrap();
+
+        assert_eq!(layout.spawn_point, (-15.0, -35.0));
+        assert_eq!(layout.coins, [(80.0, -25.0), (98.0, -25.0)]);
+
+        let [portal] = layout.portals.as_slice() else {
+            panic!("expected one portal");
+        };
+        assert_eq!(portal.name, "CavePortal");
+        assert_eq!(portal.trigger_position, (100.0, 16.0));
+        assert_eq!(portal.trigger_size, (24.0, 40.0));
+        assert_eq!(portal.target_scene, "Cave");
+        assert_eq!(portal.target_spawn, (-60.0, -20.0));
+
+        let [platform] = layout.platforms.as_slice() else {
+            panic!("expected one platform");
+        };
+        assert_eq!(platform.position, (40.0, -10.0));
+        assert!(platform.path.is_some());
+
+        assert_eq!(layout.kill_zone, Some(79.0));
+    }
+
+    #[test]
+    fn kill_zone_must_face_up() {
+        let tilted = include_str!("../fixtures/level.tscn").replace(
+            "[sub_resource type=\"WorldBoundaryShape2D\" id=\"WorldBoundaryShape2D_kill\"]\n",
+            "[sub_resource type=\"WorldBoundaryShape2D\" id=\"WorldBoundaryShape2D_kill\"]\nnormal = Vector2(1, 0)\n",
+        );
+        let scene = tscn::parse(&tilted).unwrap();
+
+        assert!(matches!(
+            SceneLayout::from_scene(&scene),
+            Err(ExportError::UnsupportedKillZone(_))
+        ));
+    }
+
+    #[test]
+    fn paths_must_start_at_the_node() {
+        let moved = include_str!("../fixtures/level.tscn").replace(
+            "[node name=\"Platform1\" type=\"AnimatableBody2D\" parent=\"Platforms\"]\nposition = Vector2(40, -20)",
+            "[node name=\"Platform1\" type=\"AnimatableBody2D\" parent=\"Platforms\"]\nposition = Vector2(40, -25)",
+        );
+        let scene = tscn::parse(&moved).unwrap();
+
+        assert!(matches!(
+            SceneLayout::from_scene(&scene),
+            Err(ExportError::PathStartMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn float_noise_is_rounded_away() {
+        assert_eq!(format_vector((2.08165e-12, -0.0)), "(x: 0.0, y: 0.0)");
+        assert_eq!(format_vector((1153.25, -93.0004)), "(x: 1153.25, y: -93.0)");
+    }
+}
@@ -0,0 +1,78 @@
+mod errors;
+mod layout;
+mod path;
+mod tscn;
+
+use errors::ExportError;
+use layout::SceneLayout;
+
+use std::path::Path;
+use std::process::ExitCode;
+
+enum Mode {
+    Export,
+    Check,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("scene-export: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), ExportError> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let (mode, scene_path, layout_path) = match args.as_slice() {
+        [mode, scene, layout] => {
+            let mode = match mode.as_str() {
+                "export" => Mode::Export,
+                "check" => Mode::Check,
+                _ => return Err(ExportError::Usage),
+            };
+            (mode, scene.as_str(), layout.as_str())
+        }
+        _ => return Err(ExportError::Usage),
+    };
+
+    let scene_source = read_file(scene_path)?;
+    let scene = tscn::parse(&scene_source)?;
+
+    let scene_file = Path::new(scene_path)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| scene_path.to_string());
+    let generated = SceneLayout::from_scene(&scene)?.to_ron(&scene_file);
+
+    match mode {
+        Mode::Export => {
+            std::fs::write(layout_path, generated).map_err(|source| ExportError::Io {
+                path: layout_path.to_string(),
+                source,
+            })?;
+            println!("Exported {scene_path} to {layout_path}");
+        }
+        Mode::Check => {
+            if read_file(layout_path)? != generated {
+                return Err(ExportError::OutOfDate {
+                    layout: layout_path.to_string(),
+                    scene: scene_path.to_string(),
+                });
+            }
+            println!("{layout_path} is up to date with {scene_path}");
+        }
+    }
+
+    Ok(())
+}
+
+fn read_file(path: &str) -> Result<String, ExportError> {
+    std::fs::read_to_string(path).map_err(|source| ExportError::Io {
+        path: path.to_string(),
+        source,
+    })
+}
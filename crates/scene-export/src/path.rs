@@ -0,0 +1,165 @@
+use crate::errors::ExportError;
+use crate::tscn::{self, TscnScene};
+
+const ANIMATION_PLAYER: &str = "AnimationPlayer";
+const MOVE_ANIMATION: &str = "move";
+const POSITION_TRACK: &str = "NodePath(\".:position\")";
+const LOOP_PING_PONG: &str = "2";
+
+/// A looping movement baked from a node's `move` animation. Mirrors
+/// `MovementPath` in `crates/server/src/elements/kinematics.rs`.
+pub struct MovementPath {
+    pub waypoints: Vec<(f32, f32)>,
+    /// Seconds for a full there-and-back cycle.
+    pub period: f32,
+    /// Godot transition curve of the keys; `1.0` is linear.
+    pub easing: f32,
+}
+
+impl MovementPath {
+    /// Reads the ping-pong `move` animation of the `AnimationPlayer` under
+    /// `node_path`, if there is one. Track values are relative to the node's
+    /// parent, so `parent_offset` is added to every waypoint.
+    pub fn from_animation(
+        scene: &TscnScene,
+        node_path: &str,
+        parent_offset: (f32, f32),
+    ) -> Result<Option<Self>, ExportError> {
+        let unsupported = |reason: &str| ExportError::UnsupportedAnimation {
+            path: node_path.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let Some(player) = scene.node(&format!("{node_path}/{ANIMATION_PLAYER}")) else {
+            return Ok(None);
+        };
+
+        let animation = player
+            .properties
+            .get("libraries")
+            .and_then(|libraries| tscn::dictionary_entry(libraries, ""))
+            .and_then(|library| scene.sub_resource(library))
+            .and_then(|library| library.properties.get("_data"))
+            .and_then(|data| tscn::dictionary_entry(data, MOVE_ANIMATION))
+            .and_then(|animation| scene.sub_resource(animation))
+            .filter(|animation| animation.resource_type == "Animation")
+            .ok_or_else(|| unsupported("no \"move\" animation in the default library"))?;
+
+        if animation.properties.get("loop_mode").map(String::as_str) != Some(LOOP_PING_PONG) {
+            return Err(unsupported("only ping-pong loops are supported"));
+        }
+
+        let length = animation
+            .properties
+            .get("length")
+            .and_then(|length| length.parse::<f32>().ok())
+            .filter(|length| *length > 0.0)
+            .ok_or_else(|| unsupported("missing or non-positive length"))?;
+
+        let keys = (0..)
+            .map_while(|track| {
+                let path = animation.properties.get(&format!("tracks/{track}/path"))?;
+                Some((track, path))
+            })
+            .find(|(_, path)| path.as_str() == POSITION_TRACK)
+            .and_then(|(track, _)| animation.properties.get(&format!("tracks/{track}/keys")))
+            .ok_or_else(|| unsupported("no position track"))?;
+
+        let times = tscn::dictionary_entry(keys, "times")
+            .and_then(tscn::parse_float_array)
+            .ok_or_else(|| unsupported("unreadable key times"))?;
+        let transitions = tscn::dictionary_entry(keys, "transitions")
+            .and_then(tscn::parse_float_array)
+            .ok_or_else(|| unsupported("unreadable key transitions"))?;
+        let values = tscn::dictionary_entry(keys, "values")
+            .and_then(tscn::parse_vector2_array)
+            .ok_or_else(|| unsupported("unreadable key values"))?;
+
+        if values.len() < 2 || times.len() != values.len() || transitions.len() != values.len() {
+            return Err(unsupported("expected at least two matching position keys"));
+        }
+
+        // The kinematics spread waypoints evenly over the animation, so the
+        // keys have to be as well.
+        let step = length / (times.len() - 1) as f32;
+        let evenly_spaced = times
+            .iter()
+            .enumerate()
+            .all(|(index, time)| (time - step * index as f32).abs() < 1e-3);
+        if !evenly_spaced {
+            return Err(unsupported(
+                "keys must be evenly spaced from 0 to the length",
+            ));
+        }
+
+        let easing = transitions[0];
+        if transitions.iter().any(|transition| *transition != easing) {
+            return Err(unsupported("all keys must share one transition"));
+        }
+
+        Ok(Some(Self {
+            waypoints: values
+                .into_iter()
+                .map(|(x, y)| (x + parent_offset.0, y + parent_offset.1))
+                .collect(),
+            period: length * 2.0,
+            easing,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../fixtures/level.tscn");
+    const PLATFORM: &str = "Platforms/Platform1";
+
+    fn bake(source: &str) -> Result<Option<MovementPath>, ExportError> {
+        let scene = tscn::parse(source).unwrap();
+        MovementPath::from_animation(&scene, PLATFORM, (0.0, 10.0))
+    }
+
+    #[test]
+    fn ping_pong_animation_is_baked() {
+        let path = bake(FIXTURE).unwrap().unwrap();
+
+        assert_eq!(
+            path.waypoints,
+            [(40.0, -10.0), (40.0, -50.0), (80.0, -50.0)]
+        );
+        assert_eq!(path.period, 4.0);
+        assert_eq!(path.easing, 0.5);
+    }
+
+    #[test]
+    fn nodes_without_animation_player_have_no_path() {
+        let scene = tscn::parse(FIXTURE).unwrap();
+
+        assert!(
+            MovementPath::from_animation(&scene, "SpawnPoint", (0.0, 0.0))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unsupported_animations_are_rejected() {
+        let linear = FIXTURE.replace("loop_mode = 2", "loop_mode = 1");
+        let uneven = FIXTURE.replace(
+            "PackedFloat32Array(0, 1, 2)",
+            "PackedFloat32Array(0, 0.5, 2)",
+        );
+        let mixed = FIXTURE.replace(
+            "PackedFloat32Array(0.5, 0.5, 0.5)",
+            "PackedFloat32Array(0.5, 1, 0.5)",
+        );
+
+        for source in [linear, uneven, mixed] {
+            assert!(matches!(
+                bake(&source),
+                Err(ExportError::UnsupportedAnimation { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,458 @@
+use crate::errors::ExportError;
+
+use std::collections::HashMap;
+
+/// A `[node ...]` section of a Godot text scene with its top-level properties.
+#[derive(Debug)]
+pub struct TscnNode {
+    pub name: String,
+    pub parent: Option<String>,
+    /// `ExtResource("id")` of the scene this node instances, if any.
+    pub instance: Option<String>,
+    pub properties: HashMap<String, String>,
+}
+
+impl TscnNode {
+    /// Path relative to the scene root, as used by the `parent` attribute.
+    pub fn path(&self) -> String {
+        match self.parent.as_deref() {
+            None => ".".to_string(),
+            Some(".") => self.name.clone(),
+            Some(parent) => format!("{parent}/{}", self.name),
+        }
+    }
+
+    pub fn position(&self) -> Result<Option<(f32, f32)>, ExportError> {
+        self.vector("position")
+    }
+
+    /// Reads a `Vector2(x, y)` property, `None` when the node leaves it at
+    /// its default.
+    pub fn vector(&self, key: &str) -> Result<Option<(f32, f32)>, ExportError> {
+        let Some(value) = self.properties.get(key) else {
+            return Ok(None);
+        };
+
+        parse_vector2(value)
+            .map(Some)
+            .ok_or_else(|| ExportError::InvalidVector {
+                path: self.path(),
+                key: key.to_string(),
+                value: value.clone(),
+            })
+    }
+
+    /// Reads a string property such as `metadata/target_scene = "Cave"`.
+    pub fn string(&self, key: &str) -> Option<String> {
+        let value = self.properties.get(key)?;
+        let unquoted = value.strip_prefix('"')?.strip_suffix('"')?;
+        Some(unquoted.replace("\\\"", "\"").replace("\\\\", "\\"))
+    }
+}
+
+/// A `[sub_resource ...]` section, e.g. an `Animation` or `AnimationLibrary`.
+#[derive(Debug)]
+pub struct TscnResource {
+    pub resource_type: String,
+    pub properties: HashMap<String, String>,
+}
+
+/// The parts of a Godot text scene the exporter reads.
+#[derive(Debug, Default)]
+pub struct TscnScene {
+    pub nodes: Vec<TscnNode>,
+    /// Keyed by the sub-resource id.
+    pub sub_resources: HashMap<String, TscnResource>,
+    /// `res://` paths of `[ext_resource ...]` sections by id.
+    pub ext_resources: HashMap<String, String>,
+}
+
+impl TscnScene {
+    pub fn node(&self, path: &str) -> Option<&TscnNode> {
+        self.nodes.iter().find(|node| node.path() == path)
+    }
+
+    /// Follows a `SubResource("id")` reference.
+    pub fn sub_resource(&self, reference: &str) -> Option<&TscnResource> {
+        let id = reference
+            .trim()
+            .strip_prefix("SubResource(\"")?
+            .strip_suffix("\")")?;
+
+        self.sub_resources.get(id)
+    }
+
+    /// `res://` path of the scene `node` instances.
+    pub fn instanced_scene(&self, node: &TscnNode) -> Option<&str> {
+        let id = node
+            .instance
+            .as_deref()?
+            .strip_prefix("ExtResource(\"")?
+            .strip_suffix("\")")?;
+
+        self.ext_resources.get(id).map(String::as_str)
+    }
+}
+
+enum Section {
+    Node,
+    SubResource(String),
+    Other,
+}
+
+pub fn parse(source: &str) -> Result<TscnScene, ExportError> {
+    let mut scene = TscnScene::default();
+    let mut section = Section::Other;
+    let mut pending: Option<(String, String)> = None;
+    let mut depth = 0i32;
+
+    for (index, raw_line) in source.lines().enumerate() {
+        let line = raw_line.trim();
+
+        // Dictionaries and arrays may span several lines; keep collecting
+        // until the brackets balance again.
+        if let Some((_, value)) = pending.as_mut() {
+            value.push('\n');
+            value.push_str(line);
+            depth += bracket_balance(line);
+
+            if depth <= 0
+                && let Some((key, value)) = pending.take()
+            {
+                depth = 0;
+                insert_property(&mut scene, &section, key, value);
+            }
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            section = parse_section(&mut scene, index + 1, line)?;
+            continue;
+        }
+
+        if let Some((key, value)) = line.split_once(" = ") {
+            let (key, value) = (key.trim().to_string(), value.trim().to_string());
+
+            depth = bracket_balance(&value);
+            if depth > 0 {
+                pending = Some((key, value));
+            } else {
+                depth = 0;
+                insert_property(&mut scene, &section, key, value);
+            }
+        }
+    }
+
+    Ok(scene)
+}
+
+fn parse_section(scene: &mut TscnScene, line: usize, header: &str) -> Result<Section, ExportError> {
+    if header.starts_with("[node ") {
+        scene.nodes.push(parse_header(line, header)?);
+        return Ok(Section::Node);
+    }
+
+    if header.starts_with("[sub_resource ") {
+        let attributes = parse_attributes(&header[1..header.len() - 1]);
+        let (Some(resource_type), Some(id)) = (attributes.get("type"), attributes.get("id")) else {
+            return Err(ExportError::MalformedHeader {
+                line,
+                header: header.to_string(),
+            });
+        };
+
+        scene.sub_resources.insert(
+            id.clone(),
+            TscnResource {
+                resource_type: resource_type.clone(),
+                properties: HashMap::new(),
+            },
+        );
+        return Ok(Section::SubResource(id.clone()));
+    }
+
+    if header.starts_with("[ext_resource ") {
+        let attributes = parse_attributes(&header[1..header.len() - 1]);
+        if let (Some(path), Some(id)) = (attributes.get("path"), attributes.get("id")) {
+            scene.ext_resources.insert(id.clone(), path.clone());
+        }
+    }
+
+    Ok(Section::Other)
+}
+
+fn insert_property(scene: &mut TscnScene, section: &Section, key: String, value: String) {
+    let properties = match section {
+        Section::Node => scene.nodes.last_mut().map(|node| &mut node.properties),
+        Section::SubResource(id) => scene
+            .sub_resources
+            .get_mut(id)
+            .map(|resource| &mut resource.properties),
+        Section::Other => None,
+    };
+
+    if let Some(properties) = properties {
+        properties.insert(key, value);
+    }
+}
+
+/// Looks up `key` in a multi-line Godot dictionary such as
+/// `{\n&"move": SubResource("x")\n}` or an animation track's `keys`.
+pub fn dictionary_entry<'a>(dictionary: &'a str, key: &str) -> Option<&'a str> {
+    dictionary.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix('&').unwrap_or(line.trim());
+        let value = rest.strip_prefix(&format!("\"{key}\":"))?;
+
+        Some(value.trim().trim_end_matches(','))
+    })
+}
+
+/// Parses `Vector2(x, y)`.
+pub fn parse_vector2(value: &str) -> Option<(f32, f32)> {
+    let components = value
+        .trim()
+        .strip_prefix("Vector2(")
+        .and_then(|rest| rest.strip_suffix(')'))?;
+
+    let mut parts = components.split(',').map(|part| part.trim().parse::<f32>());
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(x)), Some(Ok(y)), None) => Some((x, y)),
+        _ => None,
+    }
+}
+
+/// Parses `PackedFloat32Array(0, 2)`.
+pub fn parse_float_array(value: &str) -> Option<Vec<f32>> {
+    let components = value
+        .trim()
+        .strip_prefix("PackedFloat32Array(")
+        .and_then(|rest| rest.strip_suffix(')'))?;
+
+    if components.trim().is_empty() {
+        return Some(Vec::new());
+    }
+
+    components
+        .split(',')
+        .map(|part| part.trim().parse::<f32>().ok())
+        .collect()
+}
+
+/// Parses `[Vector2(1, 2), Vector2(3, 4)]`.
+pub fn parse_vector2_array(value: &str) -> Option<Vec<(f32, f32)>> {
+    let items = value
+        .trim()
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))?;
+
+    items
+        .split("Vector2(")
+        .skip(1)
+        .map(|item| {
+            let item = item.trim().trim_end_matches(',').trim();
+            parse_vector2(&format!("Vector2({item}"))
+        })
+        .collect()
+}
+
+fn parse_header(line: usize, header: &str) -> Result<TscnNode, ExportError> {
+    let malformed = || ExportError::MalformedHeader {
+        line,
+        header: header.to_string(),
+    };
+
+    let attributes = parse_attributes(&header[1..header.len() - 1]);
+    let name = attributes.get("name").cloned().ok_or_else(malformed)?;
+
+    Ok(TscnNode {
+        name,
+        parent: attributes.get("parent").cloned(),
+        instance: attributes.get("instance").cloned(),
+        properties: HashMap::new(),
+    })
+}
+
+/// Splits `node name="A" parent="B" instance=ExtResource("x")` into its
+/// `key=value` pairs, unquoting string values.
+fn parse_attributes(body: &str) -> HashMap<String, String> {
+    let mut attributes = HashMap::new();
+    let mut chars = body.chars().peekable();
+
+    while chars.peek().is_some() {
+        let mut key = String::new();
+        while let Some(c) = chars.next_if(|c| *c != '=' && !c.is_whitespace()) {
+            key.push(c);
+        }
+
+        // Bare words such as the leading `node` carry no value.
+        let has_value = chars.next_if_eq(&'=').is_some();
+
+        let mut value = String::new();
+        if has_value && chars.peek() == Some(&'"') {
+            chars.next();
+            let mut escaped = false;
+            for c in chars.by_ref() {
+                match c {
+                    '\\' if !escaped => escaped = true,
+                    '"' if !escaped => break,
+                    _ => {
+                        value.push(c);
+                        escaped = false;
+                    }
+                }
+            }
+        } else if has_value {
+            let mut depth = 0;
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() && depth == 0 {
+                    break;
+                }
+                depth += bracket_balance(c.encode_utf8(&mut [0; 4]));
+                value.push(c);
+                chars.next();
+            }
+        }
+
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+
+        if !key.is_empty() {
+            attributes.insert(key, value);
+        }
+    }
+
+    attributes
+}
+
+fn bracket_balance(text: &str) -> i32 {
+    let mut balance = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in text.chars() {
+        match c {
+            '\\' if in_string && !escaped => {
+                escaped = true;
+                continue;
+            }
+            '"' if !escaped => in_string = !in_string,
+            '{' | '[' | '(' if !in_string => balance += 1,
+            '}' | ']' | ')' if !in_string => balance -= 1,
+            _ => {}
+        }
+        escaped = false;
+    }
+
+    balance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../fixtures/level.tscn");
+
+    #[test]
+    fn attributes_are_split_and_unquoted() {
+        let attributes = parse_attributes(
+            r#"node name="Coin \"1\"" parent="Coins" instance=ExtResource("2_coin") index="0""#,
+        );
+
+        assert_eq!(attributes.get("name").unwrap(), "Coin \"1\"");
+        assert_eq!(attributes.get("parent").unwrap(), "Coins");
+        assert_eq!(
+            attributes.get("instance").unwrap(),
+            "ExtResource(\"2_coin\")"
+        );
+        assert_eq!(attributes.get("index").unwrap(), "0");
+        assert!(attributes.contains_key("node"));
+    }
+
+    #[test]
+    fn brackets_inside_strings_are_ignored() {
+        assert_eq!(bracket_balance("tracks/0/keys = {"), 1);
+        assert_eq!(bracket_balance("\"values\": [Vector2(0, 0)"), 1);
+        assert_eq!(bracket_balance(r#"text = "[(\"{""#), 0);
+        assert_eq!(bracket_balance("}"), -1);
+    }
+
+    #[test]
+    fn vectors_are_parsed() {
+        assert_eq!(parse_vector2("Vector2(12, -3.5)"), Some((12.0, -3.5)));
+        assert_eq!(parse_vector2("Vector2(1, 2, 3)"), None);
+        assert_eq!(parse_vector2("Vector3(1, 2, 3)"), None);
+    }
+
+    #[test]
+    fn fixture_nodes_and_sub_resources_are_read() {
+        let scene = parse(FIXTURE).unwrap();
+
+        let paths = scene.nodes.iter().map(TscnNode::path).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                ".",
+                "SpawnPoint",
+                "Coins",
+                "Coins/Coin1",
+                "Coins/Coin2",
+                "Portals",
+                "Portals/CavePortal",
+                "Portals/CavePortal/CollisionShape2D",
+                "Killzone",
+                "Killzone/CollisionShape2D",
+                "Platforms",
+                "Platforms/Platform1",
+                "Platforms/Platform1/AnimationPlayer",
+            ]
+        );
+
+        let portal = &scene.nodes[6];
+        assert_eq!(portal.position().unwrap(), Some((100.0, 20.0)));
+        assert_eq!(
+            portal.string("metadata/target_scene").as_deref(),
+            Some("Cave")
+        );
+
+        let shape = &scene.nodes[7];
+        let shape = scene.sub_resource(&shape.properties["shape"]).unwrap();
+        assert_eq!(shape.resource_type, "RectangleShape2D");
+        assert_eq!(shape.properties["size"], "Vector2(24, 40)");
+    }
+
+    #[test]
+    fn multi_line_values_are_kept_whole() {
+        let scene = parse(FIXTURE).unwrap();
+        let root = &scene.nodes[0];
+
+        assert!(!root.properties.contains_key("\"tile"));
+        assert_eq!(
+            root.properties["metadata/notes"],
+            "{\n\"tile = Vector2(1, 2)\": \"[not a section]\"\n}"
+        );
+        assert_eq!(root.string("metadata/title").as_deref(), Some("Level"));
+
+        let library = scene.sub_resource("SubResource(\"AnimationLibrary_plat\")");
+        let data = &library.unwrap().properties["_data"];
+        assert_eq!(
+            dictionary_entry(data, "move"),
+            Some("SubResource(\"Animation_move\")")
+        );
+    }
+
+    #[test]
+    fn arrays_are_parsed() {
+        assert_eq!(
+            parse_vector2_array("[Vector2(1170, 2.08165e-12), Vector2(1038, 35)]"),
+            Some(vec![(1170.0, 2.08165e-12), (1038.0, 35.0)])
+        );
+        assert_eq!(parse_vector2_array("[]"), Some(Vec::new()));
+        assert_eq!(parse_vector2_array("[Vector2(1, 2), 3]"), None);
+        assert_eq!(
+            parse_float_array("PackedFloat32Array(0, 1.5)"),
+            Some(vec![0.0, 1.5])
+        );
+        assert_eq!(parse_float_array("PackedFloat32Array(0, x)"), None);
+    }
+}
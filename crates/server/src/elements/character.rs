@@ -1,6 +1,11 @@
 use crate::elements::utils::DbVector2;
 
-use spacetimedb::{Identity, SpacetimeType};
+use crate::setup::expire_linkdead;
+
+use spacetimedb::{
+    ConnectionId, Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration,
+    Timestamp,
+};
 
 #[spacetimedb::table(name = player, public)]
 #[derive(Debug, Clone)]
@@ -14,7 +19,156 @@ pub struct DbPlayer {
 
     pub name: String,
 
+    #[index(btree)]
+    pub scene_id: u32,
+
     pub state: DBPlayerState,
+    /// When `state` last changed; remote clients buffer states by this time.
+    pub state_updated_at: Timestamp,
+
+    pub presence: PlayerPresence,
+
+    pub health: u32,
+    /// Bumped on every death, so clients can tell a respawn from a teleport.
+    pub deaths: u32,
+    /// Hazards do no damage before this, e.g. right after a respawn.
+    pub invulnerable_until: Option<Timestamp>,
+    /// Last checkpoint activated in the current scene; respawns happen there.
+    pub checkpoint_id: Option<u64>,
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerPresence {
+    Online,
+    /// Disconnected, but still in the world until the grace period runs out.
+    Linkdead,
+    Offline,
+}
+
+/// Expires a linkdead player once its reconnect grace period has passed.
+#[spacetimedb::table(name = linkdead_timer, scheduled(expire_linkdead))]
+#[derive(Clone, Debug)]
+pub struct LinkdeadTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+
+    pub scheduled_at: ScheduleAt,
+
+    #[unique]
+    pub identity: Identity,
+}
+
+/// The connection currently playing as an identity. A newer connection from
+/// the same identity takes the session over, so the disconnect of the one it
+/// replaced is ignored.
+#[spacetimedb::table(name = player_session)]
+#[derive(Clone, Debug)]
+pub struct PlayerSession {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub connection_id: ConnectionId,
+}
+
+impl DbPlayer {
+    pub fn is_online(&self) -> bool {
+        self.presence == PlayerPresence::Online
+    }
+
+    /// Online and linkdead players both still occupy their scene.
+    pub fn is_present(&self) -> bool {
+        self.presence != PlayerPresence::Offline
+    }
+
+    pub fn is_invulnerable(&self, now: Timestamp) -> bool {
+        self.invulnerable_until.is_some_and(|until| until > now)
+    }
+
+    /// Whether no present player is currently in `scene_id`.
+    pub fn is_scene_empty(ctx: &ReducerContext, scene_id: u32) -> bool {
+        !ctx.db
+            .player()
+            .scene_id()
+            .filter(scene_id)
+            .any(|player| player.is_present())
+    }
+}
+
+impl LinkdeadTimer {
+    pub fn schedule(ctx: &ReducerContext, identity: Identity, grace_period: TimeDuration) {
+        Self::cancel(ctx, identity);
+
+        ctx.db.linkdead_timer().insert(LinkdeadTimer {
+            scheduled_id: 0,
+            scheduled_at: (ctx.timestamp + grace_period).into(),
+            identity,
+        });
+    }
+
+    pub fn cancel(ctx: &ReducerContext, identity: Identity) {
+        ctx.db.linkdead_timer().identity().delete(identity);
+    }
+}
+
+impl PlayerSession {
+    pub fn begin(ctx: &ReducerContext) {
+        let Some(connection_id) = ctx.connection_id else {
+            return;
+        };
+
+        ctx.db.player_session().identity().delete(ctx.sender);
+        ctx.db.player_session().insert(PlayerSession {
+            identity: ctx.sender,
+            connection_id,
+        });
+    }
+
+    /// Ends the sender's session unless another connection has taken it over,
+    /// returning whether the disconnecting connection was the current one.
+    pub fn end(ctx: &ReducerContext) -> bool {
+        match ctx.db.player_session().identity().find(ctx.sender) {
+            Some(session) if Some(session.connection_id) != ctx.connection_id => false,
+            _ => {
+                ctx.db.player_session().identity().delete(ctx.sender);
+                true
+            }
+        }
+    }
+}
+
+/// When a player last reported its own death, to rate limit such reports.
+#[spacetimedb::table(name = death_report)]
+#[derive(Debug, Clone)]
+pub struct DeathReport {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub reported_at: Timestamp,
+}
+
+impl DeathReport {
+    /// Records a report from `identity`, unless its previous one was less
+    /// than `cooldown` ago, in which case nothing is recorded and false is
+    /// returned.
+    pub fn try_record(ctx: &ReducerContext, identity: Identity, cooldown: TimeDuration) -> bool {
+        let report = DeathReport {
+            identity,
+            reported_at: ctx.timestamp,
+        };
+
+        match ctx.db.death_report().identity().find(identity) {
+            Some(previous) if previous.reported_at + cooldown > ctx.timestamp => false,
+            Some(_) => {
+                ctx.db.death_report().identity().update(report);
+                true
+            }
+            None => {
+                ctx.db.death_report().insert(report);
+                true
+            }
+        }
+    }
 }
 
 #[derive(SpacetimeType, Debug, Clone)]
@@ -22,6 +176,10 @@ pub struct DBPlayerState {
     pub position: DbVector2,
     pub direction: i32,
     pub is_jumping: bool,
+    /// Client input this state resulted from. The stored state carries the
+    /// last sequence the server accepted, so the client can tell which of
+    /// its predicted states a correction applies to.
+    pub input_sequence: u32,
 }
 
 impl Default for DBPlayerState {
@@ -30,6 +188,7 @@ impl Default for DBPlayerState {
             position: DbVector2 { x: 0.0, y: 0.0 },
             direction: 0,
             is_jumping: false,
+            input_sequence: 0,
         }
     }
 }
@@ -41,4 +200,12 @@ impl DBPlayerState {
             ..Self::default()
         }
     }
+
+    /// Moves the player without losing the last accepted input sequence.
+    pub fn teleport(&mut self, position: DbVector2) {
+        *self = Self {
+            input_sequence: self.input_sequence,
+            ..Self::with_position(position)
+        };
+    }
 }
@@ -0,0 +1,38 @@
+use crate::elements::DbVector2;
+use crate::elements::character::DbPlayer;
+
+use game_rules::reach;
+use spacetimedb::ReducerContext;
+
+#[spacetimedb::table(name = checkpoint, public)]
+#[derive(Clone, Debug)]
+pub struct Checkpoint {
+    #[primary_key]
+    #[auto_inc]
+    pub checkpoint_id: u64,
+
+    #[index(btree)]
+    pub scene_id: u32,
+
+    /// Node name under `Checkpoints` in the Godot scene.
+    pub name: String,
+
+    pub position: DbVector2,
+}
+
+impl Checkpoint {
+    /// How close a player has to be to a checkpoint to activate it.
+    pub const ACTIVATION_RADIUS: f32 = reach::CHECKPOINT_ACTIVATION_RADIUS;
+
+    /// The checkpoint `player` last activated, as long as it is in the
+    /// player's current scene.
+    pub fn last_activated(ctx: &ReducerContext, player: &DbPlayer) -> Option<Self> {
+        let checkpoint = ctx
+            .db
+            .checkpoint()
+            .checkpoint_id()
+            .find(player.checkpoint_id?)?;
+
+        (checkpoint.scene_id == player.scene_id).then_some(checkpoint)
+    }
+}
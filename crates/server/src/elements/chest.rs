@@ -0,0 +1,49 @@
+use crate::elements::DbVector2;
+
+use game_rules::reach;
+use spacetimedb::{Identity, ReducerContext, Timestamp};
+
+/// Every player can open each chest once, rolling its loot table.
+#[spacetimedb::table(name = chest, public)]
+#[derive(Clone, Debug)]
+pub struct Chest {
+    #[primary_key]
+    #[auto_inc]
+    pub chest_id: u64,
+
+    #[index(btree)]
+    pub scene_id: u32,
+
+    /// Node name under `Chests` in the Godot scene.
+    pub name: String,
+
+    pub position: DbVector2,
+    pub loot_table: String,
+}
+
+#[spacetimedb::table(name = chest_opening, public)]
+#[derive(Clone, Debug)]
+pub struct ChestOpening {
+    #[primary_key]
+    #[auto_inc]
+    pub opening_id: u64,
+
+    #[index(btree)]
+    pub chest_id: u64,
+
+    pub opened_by: Identity,
+    pub opened_at: Timestamp,
+}
+
+impl Chest {
+    /// How close a player has to be to a chest to open it.
+    pub const OPEN_RADIUS: f32 = reach::CHEST_OPEN_RADIUS;
+
+    pub fn is_opened_by(&self, ctx: &ReducerContext, identity: Identity) -> bool {
+        ctx.db
+            .chest_opening()
+            .chest_id()
+            .filter(self.chest_id)
+            .any(|opening| opening.opened_by == identity)
+    }
+}
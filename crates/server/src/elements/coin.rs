@@ -1,5 +1,7 @@
 use crate::elements::DbVector2;
-use spacetimedb::Identity;
+use crate::logic::interaction::respawn_coin;
+
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table, TimeDuration};
 
 #[spacetimedb::table(name = coin, public)]
 #[derive(Clone, Debug)]
@@ -14,3 +16,26 @@ pub struct Coin {
 
     pub collected_by: Option<Identity>,
 }
+
+#[spacetimedb::table(name = coin_respawn_timer, scheduled(respawn_coin))]
+#[derive(Clone, Debug)]
+pub struct CoinRespawnTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+
+    pub scheduled_at: ScheduleAt,
+
+    #[unique]
+    pub coin_id: u64,
+}
+
+impl CoinRespawnTimer {
+    pub fn schedule(ctx: &ReducerContext, coin_id: u64, delay: TimeDuration) {
+        ctx.db.coin_respawn_timer().insert(CoinRespawnTimer {
+            scheduled_id: 0,
+            scheduled_at: (ctx.timestamp + delay).into(),
+            coin_id,
+        });
+    }
+}
@@ -0,0 +1,265 @@
+use crate::elements::DbVector2;
+
+use crate::logic::combat::respawn_enemy;
+use crate::logic::enemy_ai::update_enemies;
+
+use serde::Deserialize;
+use spacetimedb::{
+    Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp,
+};
+use std::time::Duration;
+
+/// How often the scheduler runs the enemy AI.
+pub const ENEMY_AI_INTERVAL: Duration = Duration::from_millis(100);
+
+#[spacetimedb::table(name = enemy, public)]
+#[derive(Clone, Debug)]
+pub struct Enemy {
+    #[primary_key]
+    #[auto_inc]
+    pub enemy_id: u64,
+
+    #[index(btree)]
+    pub scene_id: u32,
+
+    /// Node name under `Enemies` in the Godot scene.
+    pub name: String,
+
+    pub kind: EnemyKind,
+    pub position: DbVector2,
+    /// Where the enemy first appeared; it respawns there after dying.
+    pub spawn_point: DbVector2,
+    /// -1 facing left, 1 facing right.
+    pub direction: i32,
+    pub health: u32,
+    pub state: EnemyState,
+    /// Hits closer together than `EnemyStats::hit_cooldown` are ignored.
+    pub last_hit_at: Option<Timestamp>,
+
+    /// Points walked back and forth while patrolling, at least one.
+    pub patrol: Vec<DbVector2>,
+    /// Step along the there-and-back patrol route, see `Enemy::patrol_target`.
+    pub patrol_step: u32,
+}
+
+#[derive(SpacetimeType, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnemyKind {
+    GreenSlime,
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnemyState {
+    Patrolling,
+    Chasing(Identity),
+}
+
+/// Damage one player dealt to one enemy, so a kill can be credited to
+/// everyone who helped.
+#[spacetimedb::table(name = enemy_damage)]
+#[derive(Clone, Debug)]
+pub struct EnemyDamage {
+    #[primary_key]
+    #[auto_inc]
+    pub damage_id: u64,
+
+    #[index(btree)]
+    pub enemy_id: u64,
+
+    pub attacker: Identity,
+    pub damage: u32,
+}
+
+/// Brings a killed enemy back once `EnemyStats::respawn_delay` has passed.
+#[spacetimedb::table(name = enemy_respawn_timer, scheduled(respawn_enemy))]
+#[derive(Debug, Clone)]
+pub struct EnemyRespawnTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+
+    pub scheduled_at: ScheduleAt,
+
+    pub scene_id: u32,
+    pub name: String,
+    pub kind: EnemyKind,
+    pub spawn_point: DbVector2,
+    pub patrol: Vec<DbVector2>,
+}
+
+#[spacetimedb::table(name = enemy_ai_timer, scheduled(update_enemies))]
+#[derive(Debug, Clone)]
+pub struct EnemyAiTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Per-kind tuning, in pixels and pixels per second.
+pub struct EnemyStats {
+    pub max_health: u32,
+    pub patrol_speed: f32,
+    pub chase_speed: f32,
+    /// Players closer than this are chased.
+    pub aggro_range: f32,
+    /// A chased player further than this is given up on.
+    pub leash_range: f32,
+    pub hit_cooldown: Duration,
+    /// Health a player loses on touching the enemy.
+    pub contact_damage: u32,
+    /// Coins shared between everyone who damaged the enemy.
+    pub kill_reward: u32,
+    /// Time before a killed enemy appears again at its spawn point.
+    pub respawn_delay: Duration,
+    /// Loot table evaluated for everyone who damaged the enemy.
+    pub loot_table: &'static str,
+}
+
+impl EnemyKind {
+    pub fn stats(&self) -> EnemyStats {
+        match self {
+            EnemyKind::GreenSlime => EnemyStats {
+                max_health: 2,
+                patrol_speed: 32.0,
+                chase_speed: 48.0,
+                aggro_range: 64.0,
+                leash_range: 112.0,
+                hit_cooldown: Duration::from_millis(400),
+                contact_damage: 1,
+                kill_reward: 3,
+                respawn_delay: Duration::from_secs(30),
+                loot_table: "green_slime",
+            },
+        }
+    }
+}
+
+impl Enemy {
+    pub fn new(
+        scene_id: u32,
+        name: String,
+        kind: EnemyKind,
+        position: DbVector2,
+        patrol: Vec<DbVector2>,
+    ) -> Self {
+        Self {
+            enemy_id: 0,
+            scene_id,
+            name,
+            kind,
+            spawn_point: position.clone(),
+            position,
+            direction: 1,
+            health: kind.stats().max_health,
+            state: EnemyState::Patrolling,
+            last_hit_at: None,
+            patrol,
+            patrol_step: 0,
+        }
+    }
+
+    /// Waypoint the patrol is heading for. Steps run out through the points
+    /// and back, so four points give the route 0, 1, 2, 3, 2, 1.
+    pub fn patrol_target(&self) -> Option<&DbVector2> {
+        let count = self.patrol.len();
+        if count <= 1 {
+            return self.patrol.first();
+        }
+
+        let route_length = 2 * (count - 1);
+        let step = self.patrol_step as usize % route_length;
+        let index = if step < count {
+            step
+        } else {
+            route_length - step
+        };
+
+        self.patrol.get(index)
+    }
+
+    pub fn is_invulnerable(&self, now: Timestamp) -> bool {
+        let cooldown = TimeDuration::from(self.kind.stats().hit_cooldown);
+
+        self.last_hit_at
+            .is_some_and(|last_hit_at| last_hit_at + cooldown > now)
+    }
+
+    pub fn advance_patrol(&mut self) {
+        let route_length = 2 * self.patrol.len().saturating_sub(1).max(1);
+        self.patrol_step = ((self.patrol_step as usize + 1) % route_length) as u32;
+    }
+
+    /// Horizontal extent of the patrol; slimes never leave it, so chasing
+    /// cannot walk them off their ledge.
+    pub fn patrol_range(&self) -> (f32, f32) {
+        self.patrol
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), point| {
+                (min.min(point.x), max.max(point.x))
+            })
+    }
+}
+
+impl EnemyDamage {
+    /// Adds `damage` to what `attacker` already dealt to `enemy_id`.
+    pub fn record(ctx: &ReducerContext, enemy_id: u64, attacker: Identity, damage: u32) {
+        let existing = ctx
+            .db
+            .enemy_damage()
+            .enemy_id()
+            .filter(enemy_id)
+            .find(|entry| entry.attacker == attacker);
+
+        match existing {
+            Some(mut entry) => {
+                entry.damage += damage;
+                ctx.db.enemy_damage().damage_id().update(entry);
+            }
+            None => {
+                ctx.db.enemy_damage().insert(EnemyDamage {
+                    damage_id: 0,
+                    enemy_id,
+                    attacker,
+                    damage,
+                });
+            }
+        }
+    }
+
+    /// Removes and returns everything dealt to `enemy_id`.
+    pub fn take_all(ctx: &ReducerContext, enemy_id: u64) -> Vec<EnemyDamage> {
+        let entries: Vec<EnemyDamage> = ctx.db.enemy_damage().enemy_id().filter(enemy_id).collect();
+
+        for entry in &entries {
+            ctx.db.enemy_damage().damage_id().delete(entry.damage_id);
+        }
+
+        entries
+    }
+}
+
+impl EnemyRespawnTimer {
+    pub fn schedule(ctx: &ReducerContext, enemy: &Enemy) {
+        let delay = TimeDuration::from(enemy.kind.stats().respawn_delay);
+
+        ctx.db.enemy_respawn_timer().insert(EnemyRespawnTimer {
+            scheduled_id: 0,
+            scheduled_at: (ctx.timestamp + delay).into(),
+            scene_id: enemy.scene_id,
+            name: enemy.name.clone(),
+            kind: enemy.kind,
+            spawn_point: enemy.spawn_point.clone(),
+            patrol: enemy.patrol.clone(),
+        });
+    }
+}
+
+impl EnemyAiTimer {
+    pub fn initialize(ctx: &ReducerContext) {
+        ctx.db.enemy_ai_timer().insert(EnemyAiTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from(ENEMY_AI_INTERVAL)),
+        });
+    }
+}
@@ -0,0 +1,60 @@
+use crate::elements::DbVector2;
+use crate::elements::item::{ItemEffect, item_definition};
+
+use game_rules::reach;
+use spacetimedb::{Identity, ReducerContext};
+
+/// One stack of an item owned by a player. An item may span several rows
+/// once a stack is full.
+#[spacetimedb::table(name = inventory, public)]
+#[derive(Clone, Debug)]
+pub struct InventoryItem {
+    #[primary_key]
+    #[auto_inc]
+    pub inventory_id: u64,
+
+    #[index(btree)]
+    pub owner: Identity,
+
+    pub item_id: u32,
+    pub quantity: u32,
+
+    /// Only artifacts can be equipped, and they never stack.
+    pub equipped: bool,
+}
+
+/// Items lying in a scene after a player dropped them.
+#[spacetimedb::table(name = dropped_item, public)]
+#[derive(Clone, Debug)]
+pub struct DroppedItem {
+    #[primary_key]
+    #[auto_inc]
+    pub drop_id: u64,
+
+    #[index(btree)]
+    pub scene_id: u32,
+
+    pub item_id: u32,
+    pub quantity: u32,
+    pub position: DbVector2,
+}
+
+impl InventoryItem {
+    pub fn of(ctx: &ReducerContext, owner: Identity) -> impl Iterator<Item = Self> {
+        ctx.db.inventory().owner().filter(owner)
+    }
+
+    /// Effects of every artifact `owner` has equipped.
+    pub fn equipped_effects(ctx: &ReducerContext, owner: Identity) -> Vec<ItemEffect> {
+        Self::of(ctx, owner)
+            .filter(|stack| stack.equipped)
+            .filter_map(|stack| ctx.db.item_definition().item_id().find(stack.item_id))
+            .flat_map(|item| item.effects)
+            .collect()
+    }
+}
+
+impl DroppedItem {
+    /// How close a player has to be to pick a dropped item up.
+    pub const PICKUP_RADIUS: f32 = reach::DROPPED_ITEM_PICKUP_RADIUS;
+}
@@ -0,0 +1,53 @@
+use serde::Deserialize;
+use spacetimedb::SpacetimeType;
+
+#[derive(SpacetimeType, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ItemRarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+#[derive(SpacetimeType, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    /// Collected for its own sake; stacks up to `max_stack`.
+    Treasure,
+    /// Equippable, applying its effects while equipped.
+    Artifact,
+}
+
+/// Stat change an equipped artifact applies to its holder.
+#[derive(SpacetimeType, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ItemEffect {
+    /// Fraction added to the base run speed; 0.1 is 10% faster.
+    SpeedBonus(f32),
+    /// Fraction added to the base jump velocity.
+    JumpBonus(f32),
+}
+
+#[spacetimedb::table(name = item_definition, public)]
+#[derive(Clone, Debug)]
+pub struct ItemDefinition {
+    #[primary_key]
+    #[auto_inc]
+    pub item_id: u32,
+
+    #[unique]
+    pub name: String,
+
+    pub description: String,
+    pub kind: ItemKind,
+    pub rarity: ItemRarity,
+
+    /// Most units one inventory row can hold.
+    pub max_stack: u32,
+    pub effects: Vec<ItemEffect>,
+}
+
+impl ItemDefinition {
+    pub fn is_equippable(&self) -> bool {
+        self.kind == ItemKind::Artifact
+    }
+}
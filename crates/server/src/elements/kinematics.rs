@@ -0,0 +1,45 @@
+use crate::elements::DbVector2;
+
+use game_rules::kinematics;
+use serde::Deserialize;
+use spacetimedb::SpacetimeType;
+
+/// A looping ping-pong movement through `waypoints`, baked by `scene-export`
+/// from the node's `move` animation. Evaluated with `game_rules::kinematics`,
+/// like on the client, so both sides agree on a body's position at any world time.
+#[derive(SpacetimeType, Deserialize, Clone, Debug)]
+pub struct MovementPath {
+    pub waypoints: Vec<DbVector2>,
+    /// Seconds for a full there-and-back cycle.
+    pub period: f32,
+    /// Godot transition curve applied to every segment; `1.0` is linear.
+    pub easing: f32,
+}
+
+impl MovementPath {
+    pub fn is_valid(&self) -> bool {
+        kinematics::is_valid_path(self.waypoints.len(), self.period, self.easing)
+    }
+
+    /// Position at `world_seconds` into the scene clock.
+    pub fn position_at(&self, world_seconds: f64) -> DbVector2 {
+        let segment = kinematics::path_segment(
+            self.waypoints.len(),
+            self.period,
+            self.easing,
+            world_seconds,
+        );
+
+        let Some((segment, weight)) = segment else {
+            return self.waypoints.first().cloned().unwrap_or_default();
+        };
+
+        let from = &self.waypoints[segment];
+        let to = &self.waypoints[segment + 1];
+
+        DbVector2::new(
+            kinematics::lerp(from.x, to.x, weight),
+            kinematics::lerp(from.y, to.y, weight),
+        )
+    }
+}
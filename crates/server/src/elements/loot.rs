@@ -0,0 +1,293 @@
+use crate::elements::item::ItemRarity;
+
+use serde::Deserialize;
+use spacetimedb::rand::Rng;
+use spacetimedb::{ReducerContext, SpacetimeType};
+
+#[derive(SpacetimeType, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LootDistribution {
+    /// Rolled once; the loot is dropped in the world for anyone to pick up.
+    Shared,
+    /// Rolled once for every recipient, straight into their inventory.
+    PerPlayer,
+}
+
+#[derive(SpacetimeType, Debug, Clone, PartialEq)]
+pub struct LootItem {
+    pub item_id: u32,
+    pub rarity: ItemRarity,
+    pub min_quantity: u32,
+    pub max_quantity: u32,
+}
+
+#[derive(SpacetimeType, Debug, Clone, PartialEq)]
+pub struct WeightedLoot {
+    pub item: LootItem,
+    pub weight: u32,
+}
+
+#[derive(SpacetimeType, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RarityTier {
+    pub rarity: ItemRarity,
+    pub weight: u32,
+}
+
+/// Loot tables from `crates/server/items/loot.ron`, with item names
+/// resolved against `item_definition`.
+#[spacetimedb::table(name = loot_table)]
+#[derive(Clone, Debug)]
+pub struct LootTable {
+    #[primary_key]
+    pub name: String,
+
+    pub distribution: LootDistribution,
+    /// Dropped on every evaluation, before any roll.
+    pub guaranteed: Vec<LootItem>,
+
+    pub rolls: u32,
+    /// Weight of a roll coming up empty.
+    pub nothing_weight: u32,
+    /// When set, a roll first picks a tier and then an entry of that rarity;
+    /// otherwise it picks directly from `entries`.
+    pub tiers: Vec<RarityTier>,
+    pub entries: Vec<WeightedLoot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LootDrop {
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+impl LootItem {
+    fn roll<R: Rng>(&self, rng: &mut R) -> LootDrop {
+        LootDrop {
+            item_id: self.item_id,
+            quantity: rng.gen_range(self.min_quantity..=self.max_quantity),
+        }
+    }
+}
+
+impl LootTable {
+    pub fn find(ctx: &ReducerContext, name: &str) -> Result<Self, String> {
+        ctx.db
+            .loot_table()
+            .name()
+            .find(name.to_string())
+            .ok_or(format!("Loot table {name} does not exist"))
+    }
+
+    /// Evaluates the table once. Drops of the same item are merged.
+    pub fn roll<R: Rng>(&self, rng: &mut R) -> Vec<LootDrop> {
+        let mut drops: Vec<LootDrop> = Vec::new();
+
+        let rolled = self
+            .guaranteed
+            .iter()
+            .map(|item| item.roll(rng))
+            .collect::<Vec<_>>();
+        let picked = (0..self.rolls)
+            .filter_map(|_| self.pick(rng).map(|item| item.roll(rng)))
+            .collect::<Vec<_>>();
+
+        for drop in rolled.into_iter().chain(picked) {
+            match drops.iter_mut().find(|other| other.item_id == drop.item_id) {
+                Some(other) => other.quantity += drop.quantity,
+                None => drops.push(drop),
+            }
+        }
+
+        drops
+    }
+
+    fn pick<R: Rng>(&self, rng: &mut R) -> Option<&LootItem> {
+        if self.tiers.is_empty() {
+            let entries = self.entries.iter().map(|entry| (entry, entry.weight));
+            return pick_weighted(rng, self.nothing_weight, entries).map(|entry| &entry.item);
+        }
+
+        let tiers = self.tiers.iter().map(|tier| (tier, tier.weight));
+        let tier = pick_weighted(rng, self.nothing_weight, tiers)?;
+
+        let entries = self
+            .entries
+            .iter()
+            .filter(|entry| entry.item.rarity == tier.rarity)
+            .map(|entry| (entry, entry.weight));
+        pick_weighted(rng, 0, entries).map(|entry| &entry.item)
+    }
+}
+
+/// Picks one option with probability proportional to its weight, or none
+/// with probability proportional to `nothing_weight`.
+fn pick_weighted<'a, T, R: Rng>(
+    rng: &mut R,
+    nothing_weight: u32,
+    options: impl Iterator<Item = (&'a T, u32)> + Clone,
+) -> Option<&'a T> {
+    let total: u64 = nothing_weight as u64
+        + options
+            .clone()
+            .map(|(_, weight)| weight as u64)
+            .sum::<u64>();
+    if total == 0 {
+        return None;
+    }
+
+    let mut roll = rng.gen_range(0..total);
+    for (option, weight) in options {
+        if roll < weight as u64 {
+            return Some(option);
+        }
+        roll -= weight as u64;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spacetimedb::rand::SeedableRng;
+    use spacetimedb::rand::rngs::StdRng;
+
+    const ROLLS: usize = 100_000;
+    /// Allowed difference between the observed and expected share of rolls.
+    const TOLERANCE: f64 = 0.01;
+
+    fn item(item_id: u32, rarity: ItemRarity) -> LootItem {
+        LootItem {
+            item_id,
+            rarity,
+            min_quantity: 1,
+            max_quantity: 1,
+        }
+    }
+
+    fn table(nothing_weight: u32, tiers: Vec<RarityTier>, entries: Vec<WeightedLoot>) -> LootTable {
+        LootTable {
+            name: "test".to_string(),
+            distribution: LootDistribution::PerPlayer,
+            guaranteed: Vec::new(),
+            rolls: 1,
+            nothing_weight,
+            tiers,
+            entries,
+        }
+    }
+
+    /// Share of `ROLLS` evaluations that dropped each of `item_ids`.
+    fn shares(table: &LootTable, item_ids: &[u32]) -> Vec<f64> {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut counts = vec![0usize; item_ids.len()];
+
+        for _ in 0..ROLLS {
+            for drop in table.roll(&mut rng) {
+                if let Some(index) = item_ids.iter().position(|id| *id == drop.item_id) {
+                    counts[index] += 1;
+                }
+            }
+        }
+
+        counts
+            .into_iter()
+            .map(|count| count as f64 / ROLLS as f64)
+            .collect()
+    }
+
+    fn assert_close(observed: f64, expected: f64) {
+        assert!(
+            (observed - expected).abs() < TOLERANCE,
+            "observed share {observed:.4}, expected {expected:.4}"
+        );
+    }
+
+    #[test]
+    fn weighted_entries_follow_their_weights() {
+        let table = table(
+            4,
+            Vec::new(),
+            vec![
+                WeightedLoot {
+                    item: item(1, ItemRarity::Common),
+                    weight: 1,
+                },
+                WeightedLoot {
+                    item: item(2, ItemRarity::Common),
+                    weight: 3,
+                },
+            ],
+        );
+
+        let shares = shares(&table, &[1, 2]);
+        assert_close(shares[0], 1.0 / 8.0);
+        assert_close(shares[1], 3.0 / 8.0);
+    }
+
+    #[test]
+    fn tiers_are_rolled_before_entries() {
+        let table = table(
+            0,
+            vec![
+                RarityTier {
+                    rarity: ItemRarity::Common,
+                    weight: 9,
+                },
+                RarityTier {
+                    rarity: ItemRarity::Rare,
+                    weight: 1,
+                },
+            ],
+            vec![
+                WeightedLoot {
+                    item: item(1, ItemRarity::Common),
+                    weight: 1,
+                },
+                WeightedLoot {
+                    item: item(2, ItemRarity::Common),
+                    weight: 1,
+                },
+                // Heavily weighted, but only within its own tier.
+                WeightedLoot {
+                    item: item(3, ItemRarity::Rare),
+                    weight: 100,
+                },
+            ],
+        );
+
+        let shares = shares(&table, &[1, 2, 3]);
+        assert_close(shares[0], 0.45);
+        assert_close(shares[1], 0.45);
+        assert_close(shares[2], 0.1);
+    }
+
+    #[test]
+    fn guaranteed_drops_always_come_with_rolled_quantities() {
+        let mut table = table(1, Vec::new(), Vec::new());
+        table.guaranteed = vec![LootItem {
+            item_id: 1,
+            rarity: ItemRarity::Common,
+            min_quantity: 2,
+            max_quantity: 4,
+        }];
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut seen = [false; 3];
+        for _ in 0..1_000 {
+            let drops = table.roll(&mut rng);
+            assert_eq!(drops.len(), 1);
+            assert!((2..=4).contains(&drops[0].quantity));
+            seen[drops[0].quantity as usize - 2] = true;
+        }
+
+        assert_eq!(seen, [true; 3]);
+    }
+
+    #[test]
+    fn empty_table_drops_nothing() {
+        let table = table(0, Vec::new(), Vec::new());
+        let mut rng = StdRng::seed_from_u64(7);
+
+        assert!(table.roll(&mut rng).is_empty());
+    }
+}
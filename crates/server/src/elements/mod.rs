@@ -1,7 +1,19 @@
 pub mod character;
+pub mod checkpoint;
+pub mod chest;
 pub mod coin;
+pub mod enemy;
+pub mod inventory;
+pub mod item;
+pub mod kinematics;
+pub mod loot;
+pub mod movement;
+pub mod platform;
 pub mod player_score;
+pub mod portal;
+pub mod server_config;
 pub mod utils;
+pub mod world_clock;
 pub mod world_scene;
 
 pub use utils::*;
@@ -0,0 +1,131 @@
+use crate::elements::item::ItemEffect;
+
+use game_rules::movement::MovementLimits;
+use spacetimedb::{Identity, ReducerContext, Table, TimeDuration, Timestamp};
+
+/// What the server last accepted from a player's `send_player_state`.
+#[spacetimedb::table(name = movement_tracker)]
+#[derive(Clone, Debug)]
+pub struct MovementTracker {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub last_update_at: Timestamp,
+    /// Height the current jump started from, while the player is jumping.
+    pub jump_origin_y: Option<f32>,
+
+    /// Implausible updates within the current strike window.
+    pub strikes: u32,
+    pub last_strike_at: Option<Timestamp>,
+
+    /// Until then, updates that fail the checks are dropped without a strike,
+    /// as ones sent before the server moved the player may still arrive.
+    pub settle_until: Option<Timestamp>,
+
+    /// Recent updates, draining at `ServerConfig::state_update_rate`.
+    pub update_backlog: f32,
+    pub backlog_drained_at: Timestamp,
+}
+
+/// Players that kept sending implausible movement.
+#[spacetimedb::table(name = movement_suspicion)]
+#[derive(Clone, Debug)]
+pub struct MovementSuspicion {
+    #[primary_key]
+    pub identity: Identity,
+
+    /// Times the player ran out of strikes.
+    pub offences: u32,
+    pub last_offence_at: Timestamp,
+    pub last_violation: String,
+}
+
+/// Movement limits of a player with `effects` equipped; the client applies
+/// the same bonuses in `BasicPlayer::apply_item_effects`.
+pub fn movement_limits(effects: &[ItemEffect]) -> MovementLimits {
+    let mut speed_bonus = 0.0;
+    let mut jump_bonus = 0.0;
+    for effect in effects {
+        match effect {
+            ItemEffect::SpeedBonus(bonus) => speed_bonus += bonus,
+            ItemEffect::JumpBonus(bonus) => jump_bonus += bonus,
+        }
+    }
+
+    MovementLimits::with_bonuses(speed_bonus, jump_bonus)
+}
+
+impl MovementTracker {
+    pub fn new(identity: Identity, now: Timestamp) -> Self {
+        Self {
+            identity,
+            last_update_at: now,
+            jump_origin_y: None,
+            strikes: 0,
+            last_strike_at: None,
+            settle_until: None,
+            update_backlog: 0.0,
+            backlog_drained_at: now,
+        }
+    }
+
+    /// Starts checking a player's movement over from where its row says,
+    /// e.g. when it comes online.
+    pub fn restart(ctx: &ReducerContext, identity: Identity) {
+        Self::new(identity, ctx.timestamp).save(ctx);
+    }
+
+    /// Starts over from where the server just moved the player to. Updates
+    /// still in flight from before cannot pass the checks, so for `period`
+    /// they are dropped instead of counting as strikes.
+    pub fn settle(ctx: &ReducerContext, identity: Identity, period: TimeDuration) {
+        let mut tracker = ctx
+            .db
+            .movement_tracker()
+            .identity()
+            .find(identity)
+            .unwrap_or_else(|| Self::new(identity, ctx.timestamp));
+
+        tracker.last_update_at = ctx.timestamp;
+        tracker.jump_origin_y = None;
+        tracker.settle_until = Some(ctx.timestamp + period);
+        tracker.save(ctx);
+    }
+
+    pub fn save(self, ctx: &ReducerContext) {
+        if ctx
+            .db
+            .movement_tracker()
+            .identity()
+            .find(self.identity)
+            .is_some()
+        {
+            ctx.db.movement_tracker().identity().update(self);
+        } else {
+            ctx.db.movement_tracker().insert(self);
+        }
+    }
+
+    /// Leaky bucket rate limit: the backlog drains `rate` updates a second
+    /// and holds at most `burst`. Returns false if this update does not fit.
+    pub fn admit_update(&mut self, now: Timestamp, rate: f32, burst: f32) -> bool {
+        let elapsed_micros =
+            now.to_micros_since_unix_epoch() - self.backlog_drained_at.to_micros_since_unix_epoch();
+        let drained = elapsed_micros.max(0) as f32 / 1_000_000.0 * rate;
+
+        self.update_backlog = (self.update_backlog - drained).max(0.0);
+        self.backlog_drained_at = now;
+
+        if self.update_backlog + 1.0 > burst {
+            return false;
+        }
+
+        self.update_backlog += 1.0;
+
+        true
+    }
+
+    pub fn is_settling(&self, now: Timestamp) -> bool {
+        self.settle_until.is_some_and(|until| until > now)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::elements::DbVector2;
+use crate::elements::kinematics::MovementPath;
+
+#[spacetimedb::table(name = platform, public)]
+#[derive(Clone, Debug)]
+pub struct Platform {
+    #[primary_key]
+    #[auto_inc]
+    pub platform_id: u64,
+
+    #[index(btree)]
+    pub scene_id: u32,
+
+    /// Node name under `Platforms` in the Godot scene.
+    pub name: String,
+
+    /// Where the platform sits when it has no path.
+    pub rest_position: DbVector2,
+    pub path: Option<MovementPath>,
+}
+
+impl Platform {
+    pub fn position_at(&self, world_seconds: f64) -> DbVector2 {
+        match &self.path {
+            Some(path) => path.position_at(world_seconds),
+            None => self.rest_position.clone(),
+        }
+    }
+}
@@ -26,6 +26,10 @@ impl PlayerScore {
     }
 
     pub fn add_coin(&mut self) {
-        self.coins_collected += 1;
+        self.add_coins(1);
+    }
+
+    pub fn add_coins(&mut self, amount: u32) {
+        self.coins_collected += amount;
     }
 }
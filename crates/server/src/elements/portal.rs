@@ -0,0 +1,42 @@
+use crate::elements::DbVector2;
+
+use spacetimedb::ReducerContext;
+
+#[spacetimedb::table(name = portal, public)]
+#[derive(Clone, Debug)]
+pub struct Portal {
+    #[primary_key]
+    #[auto_inc]
+    pub portal_id: u64,
+
+    #[index(btree)]
+    pub source_scene_id: u32,
+    /// Name of the portal node in the source scene, unique within it.
+    pub name: String,
+
+    /// Center of the trigger area in the source scene.
+    pub trigger_position: DbVector2,
+    /// Full width and height of the trigger area.
+    pub trigger_size: DbVector2,
+
+    pub target_scene_id: u32,
+    pub target_spawn: DbVector2,
+}
+
+impl Portal {
+    pub fn find(ctx: &ReducerContext, scene_id: u32, name: &str) -> Option<Self> {
+        ctx.db
+            .portal()
+            .source_scene_id()
+            .filter(scene_id)
+            .find(|portal| portal.name == name)
+    }
+
+    pub fn contains(&self, position: &DbVector2) -> bool {
+        let half_width = self.trigger_size.x / 2.0;
+        let half_height = self.trigger_size.y / 2.0;
+
+        (position.x - self.trigger_position.x).abs() <= half_width
+            && (position.y - self.trigger_position.y).abs() <= half_height
+    }
+}
@@ -0,0 +1,111 @@
+use game_rules::movement::MovementAllowance;
+use spacetimedb::{Identity, ReducerContext, Table, TimeDuration};
+use std::time::Duration;
+
+/// Module-wide tunables, kept in a single row that `init` seeds from
+/// `Default`. No reducer writes it afterwards, so new values only take
+/// effect on a freshly initialized database.
+#[spacetimedb::table(name = server_config)]
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    #[primary_key]
+    pub config_id: u32,
+
+    /// How long a disconnected player stays linkdead before going offline.
+    pub linkdead_grace_period: TimeDuration,
+
+    pub player_max_health: u32,
+    /// Invulnerability after taking a hit that was not fatal.
+    pub hit_invulnerability: TimeDuration,
+    /// Invulnerability after respawning.
+    pub respawn_invulnerability: TimeDuration,
+    /// How far above a kill zone a player's last known position may be when
+    /// it reports falling in, to allow for latency and its collision shape.
+    pub kill_zone_tolerance: f32,
+    /// Minimum time between two deaths a player reports itself.
+    pub death_report_cooldown: TimeDuration,
+
+    /// Most inventory rows (stacks) one player can hold.
+    pub inventory_slots: u32,
+    /// Most artifacts one player can have equipped at once.
+    pub artifact_slots: u32,
+
+    /// Extra distance every movement check allows, for jitter and rounding.
+    pub movement_tolerance: f32,
+    /// Extra speed a moving platform may add to a player riding it.
+    pub platform_speed_allowance: f32,
+    /// Fastest a falling player may drop.
+    pub max_fall_speed: f32,
+    /// Implausible updates within `movement_strike_window` before the player
+    /// is recorded as suspicious.
+    pub movement_strike_limit: u32,
+    pub movement_strike_window: TimeDuration,
+    /// How long after the server moved a player itself updates it rejects
+    /// are dropped without a strike, see `MovementTracker::settle`.
+    pub movement_settle_period: TimeDuration,
+    /// Sustained `send_player_state` calls per second one player may make.
+    pub state_update_rate: f32,
+    /// Calls a player may make in quick succession on top of the rate, as the
+    /// client sends direction and jump changes right away.
+    pub state_update_burst: f32,
+
+    /// Identity allowed to call administrative reducers such as `grant_item`;
+    /// the publisher of the module.
+    pub admin: Option<Identity>,
+}
+
+impl ServerConfig {
+    const CONFIG_ID: u32 = 0;
+
+    pub fn initialize(ctx: &ReducerContext) {
+        ctx.db.server_config().insert(Self {
+            admin: Some(ctx.sender),
+            ..Self::default()
+        });
+    }
+
+    pub fn get(ctx: &ReducerContext) -> Self {
+        ctx.db
+            .server_config()
+            .config_id()
+            .find(Self::CONFIG_ID)
+            .unwrap_or_default()
+    }
+
+    pub fn is_admin(&self, identity: Identity) -> bool {
+        self.admin == Some(identity)
+    }
+
+    pub fn movement_allowance(&self) -> MovementAllowance {
+        MovementAllowance {
+            tolerance: self.movement_tolerance,
+            platform_speed: self.platform_speed_allowance,
+            max_fall_speed: self.max_fall_speed,
+        }
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            config_id: Self::CONFIG_ID,
+            linkdead_grace_period: TimeDuration::from(Duration::from_secs(30)),
+            player_max_health: 3,
+            hit_invulnerability: TimeDuration::from(Duration::from_secs(1)),
+            respawn_invulnerability: TimeDuration::from(Duration::from_secs(2)),
+            kill_zone_tolerance: 48.0,
+            death_report_cooldown: TimeDuration::from(Duration::from_secs(1)),
+            inventory_slots: 24,
+            artifact_slots: 2,
+            movement_tolerance: 8.0,
+            platform_speed_allowance: 80.0,
+            max_fall_speed: 1200.0,
+            movement_strike_limit: 5,
+            movement_strike_window: TimeDuration::from(Duration::from_secs(10)),
+            movement_settle_period: TimeDuration::from(Duration::from_secs(1)),
+            state_update_rate: 30.0,
+            state_update_burst: 10.0,
+            admin: None,
+        }
+    }
+}
@@ -1,7 +1,10 @@
+use game_rules::reach;
+use serde::Deserialize;
 use spacetimedb::SpacetimeType;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 
-#[derive(SpacetimeType, Clone, Debug, Default)]
+#[derive(SpacetimeType, Deserialize, Clone, Debug, Default)]
 pub struct DbVector2 {
     pub x: f32,
     pub y: f32,
@@ -26,4 +29,14 @@ impl DbVector2 {
     pub fn new(x: f32, y: f32) -> Self {
         Self { x, y }
     }
+
+    pub fn distance_to(&self, other: &DbVector2) -> f32 {
+        reach::distance((self.x, self.y), (other.x, other.y))
+    }
+}
+
+impl fmt::Display for DbVector2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
 }
@@ -0,0 +1,82 @@
+use crate::setup::update_timestamp;
+
+use spacetimedb::{ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+use std::time::Duration;
+
+/// How often the scheduler advances every scene clock.
+const WORLD_CLOCK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Shared time of one scene, owned by the server. Clients only read it.
+#[spacetimedb::table(name = world_clock, public)]
+#[derive(Debug, Clone)]
+pub struct WorldClock {
+    #[primary_key]
+    pub scene_id: u32,
+
+    /// Zero point of the scene's animations, reset when the scene fills up again.
+    pub started_at: Timestamp,
+
+    pub tick: u64,
+    pub server_time: Timestamp,
+}
+
+#[spacetimedb::table(name = world_clock_timer, scheduled(update_timestamp))]
+#[derive(Debug, Clone)]
+pub struct WorldClockTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+
+    pub scheduled_at: ScheduleAt,
+}
+
+impl WorldClock {
+    pub fn initialize(ctx: &ReducerContext, scene_id: u32) {
+        ctx.db.world_clock().insert(WorldClock {
+            scene_id,
+            started_at: ctx.timestamp,
+            tick: 0,
+            server_time: ctx.timestamp,
+        });
+    }
+
+    pub fn restart(ctx: &ReducerContext, scene_id: u32) -> Result<(), String> {
+        let mut clock = ctx
+            .db
+            .world_clock()
+            .scene_id()
+            .find(scene_id)
+            .ok_or("World clock not found")?;
+
+        clock.started_at = ctx.timestamp;
+        clock.server_time = ctx.timestamp;
+        ctx.db.world_clock().scene_id().update(clock);
+
+        Ok(())
+    }
+
+    /// Seconds of world time at `now`, the time base of every `MovementPath`.
+    pub fn world_seconds(&self, now: Timestamp) -> f64 {
+        let elapsed_micros =
+            now.to_micros_since_unix_epoch() - self.started_at.to_micros_since_unix_epoch();
+
+        elapsed_micros as f64 / 1_000_000.0
+    }
+
+    pub fn advance(ctx: &ReducerContext) {
+        for mut clock in ctx.db.world_clock().iter() {
+            clock.tick += 1;
+            clock.server_time = ctx.timestamp;
+            ctx.db.world_clock().scene_id().update(clock);
+        }
+    }
+}
+
+impl WorldClockTimer {
+    pub fn initialize(ctx: &ReducerContext) {
+        ctx.db.world_clock_timer().insert(WorldClockTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from(WORLD_CLOCK_INTERVAL)),
+        });
+    }
+}
@@ -1,4 +1,5 @@
-use spacetimedb::{ReducerContext, Table, Timestamp};
+use game_rules::reach;
+use spacetimedb::{Identity, ReducerContext, TimeDuration, Timestamp};
 
 use crate::elements::DbVector2;
 
@@ -10,35 +11,76 @@ pub struct WorldScene {
     pub scene_id: u32,
 
     pub name: String,
-    pub creation_time: Timestamp,
-    pub last_update_time: Timestamp,
 
     pub spawn_point: DbVector2,
+
+    pub coin_respawn_delay: TimeDuration,
+    pub coin_pickup_radius: f32,
+
+    /// Reaching this completes the scene.
+    pub goal: Option<DbVector2>,
+    /// Loot table rolled for every coin picked up.
+    pub coin_loot_table: Option<String>,
+    /// Loot table rolled the first time a player completes the scene.
+    pub completion_loot_table: Option<String>,
+}
+
+/// First completion of a scene by a player.
+#[spacetimedb::table(name = scene_completion, public)]
+#[derive(Clone, Debug)]
+pub struct SceneCompletion {
+    #[primary_key]
+    #[auto_inc]
+    pub completion_id: u64,
+
+    #[index(btree)]
+    pub identity: Identity,
+
+    pub scene_id: u32,
+    pub completed_at: Timestamp,
+}
+
+/// Where a scene's kill zone starts. Only the server checks reported deaths
+/// against it, so it is kept out of the public `world_scene` table.
+#[spacetimedb::table(name = kill_zone)]
+#[derive(Debug, Clone)]
+pub struct KillZone {
+    #[primary_key]
+    pub scene_id: u32,
+
+    /// Players below this height have fallen out of the level.
+    pub y: f32,
 }
 
 impl WorldScene {
-    pub fn new(name: String, spawn_point: DbVector2, creation_time: Timestamp) -> Self {
+    pub fn new(
+        name: String,
+        spawn_point: DbVector2,
+        coin_respawn_delay: TimeDuration,
+        coin_pickup_radius: f32,
+    ) -> Self {
         Self {
             scene_id: 0,
             name,
-            creation_time,
-            last_update_time: creation_time,
             spawn_point,
+            coin_respawn_delay,
+            coin_pickup_radius,
+            goal: None,
+            coin_loot_table: None,
+            completion_loot_table: None,
         }
     }
 
-    pub fn set_creation_time(ctx: &ReducerContext, creation_time: Timestamp) -> Result<(), String> {
-        let mut world_scene = ctx
-            .db
-            .world_scene()
-            .iter()
-            .find(|scene| scene.scene_id == 1)
-            .ok_or("World scene not found")?;
-
-        world_scene.creation_time = creation_time;
-        world_scene.last_update_time = creation_time;
-        ctx.db.world_scene().scene_id().update(world_scene);
+    /// How close a player has to be to the goal to complete the scene.
+    pub const GOAL_RADIUS: f32 = reach::GOAL_RADIUS;
+}
 
-        Ok(())
+impl SceneCompletion {
+    pub fn exists(ctx: &ReducerContext, identity: Identity, scene_id: u32) -> bool {
+        ctx.db
+            .scene_completion()
+            .identity()
+            .filter(identity)
+            .any(|completion| completion.scene_id == scene_id)
     }
 }
@@ -0,0 +1,210 @@
+use crate::elements::character::player;
+use crate::elements::enemy::{Enemy, EnemyDamage, EnemyRespawnTimer, enemy};
+use crate::elements::player_score::{PlayerScore, player_score};
+use crate::logic::loot::{LootSource, award_loot};
+
+use spacetimedb::{Identity, ReducerContext, Table, reducer};
+use std::fmt;
+
+/// Damage dealt by landing on an enemy.
+const STOMP_DAMAGE: u32 = 1;
+/// How far off-center a stomp may land.
+const STOMP_HORIZONTAL_REACH: f32 = 16.0;
+/// How far above the enemy the player may be when the stomp lands.
+const STOMP_VERTICAL_REACH: f32 = 32.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StompEnemyError {
+    PlayerNotRegistered,
+    PlayerNotOnline,
+    EnemyNotFound(u64),
+    WrongScene(u64),
+    OutOfReach {
+        enemy_id: u64,
+        offset_x: f32,
+        offset_y: f32,
+    },
+    NotAbove(u64),
+    Invulnerable(u64),
+}
+
+impl fmt::Display for StompEnemyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlayerNotRegistered => write!(f, "Player not registered"),
+            Self::PlayerNotOnline => write!(f, "Player is not online"),
+            Self::EnemyNotFound(enemy_id) => write!(f, "Enemy {enemy_id} not found"),
+            Self::WrongScene(enemy_id) => write!(f, "Enemy {enemy_id} is in another scene"),
+            Self::OutOfReach {
+                enemy_id,
+                offset_x,
+                offset_y,
+            } => write!(
+                f,
+                "Enemy {enemy_id} is out of reach ({offset_x:.1}, {offset_y:.1} away)"
+            ),
+            Self::NotAbove(enemy_id) => {
+                write!(f, "Enemy {enemy_id} can only be stomped from above")
+            }
+            Self::Invulnerable(enemy_id) => write!(f, "Enemy {enemy_id} was just hit"),
+        }
+    }
+}
+
+/// Damages an enemy the caller landed on, checked against the last position
+/// the server has for both of them.
+#[reducer]
+pub fn stomp_enemy(ctx: &ReducerContext, enemy_id: u64) -> Result<(), StompEnemyError> {
+    log::trace!("Player {} is stomping enemy {}", ctx.sender, enemy_id);
+
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or(StompEnemyError::PlayerNotRegistered)?;
+
+    if !player.is_online() {
+        return Err(StompEnemyError::PlayerNotOnline);
+    }
+
+    let mut enemy = ctx
+        .db
+        .enemy()
+        .enemy_id()
+        .find(enemy_id)
+        .ok_or(StompEnemyError::EnemyNotFound(enemy_id))?;
+
+    if enemy.scene_id != player.scene_id {
+        return Err(StompEnemyError::WrongScene(enemy_id));
+    }
+
+    // Godot's y axis points down, so a player above the enemy has a smaller y.
+    let offset_x = player.state.position.x - enemy.position.x;
+    let offset_y = enemy.position.y - player.state.position.y;
+
+    if offset_y < 0.0 {
+        return Err(StompEnemyError::NotAbove(enemy_id));
+    }
+
+    if offset_x.abs() > STOMP_HORIZONTAL_REACH || offset_y > STOMP_VERTICAL_REACH {
+        return Err(StompEnemyError::OutOfReach {
+            enemy_id,
+            offset_x,
+            offset_y,
+        });
+    }
+
+    if enemy.is_invulnerable(ctx.timestamp) {
+        return Err(StompEnemyError::Invulnerable(enemy_id));
+    }
+
+    let damage = STOMP_DAMAGE.min(enemy.health);
+    EnemyDamage::record(ctx, enemy_id, ctx.sender, damage);
+
+    enemy.health -= damage;
+    enemy.last_hit_at = Some(ctx.timestamp);
+
+    if enemy.health > 0 {
+        let enemy = ctx.db.enemy().enemy_id().update(enemy);
+
+        log::info!(
+            "Player {} stomped enemy {} ({}), {} health left",
+            player.name,
+            enemy.enemy_id,
+            enemy.name,
+            enemy.health
+        );
+
+        return Ok(());
+    }
+
+    kill_enemy(ctx, &enemy, ctx.sender);
+
+    Ok(())
+}
+
+/// Removes a dead enemy and shares its reward between everyone who hurt it,
+/// by damage dealt. What does not divide evenly goes to the killer. The
+/// enemy's loot table is evaluated for the same players, and the enemy is
+/// scheduled to respawn at its spawn point.
+fn kill_enemy(ctx: &ReducerContext, enemy: &Enemy, killer: Identity) {
+    ctx.db.enemy().enemy_id().delete(enemy.enemy_id);
+    EnemyRespawnTimer::schedule(ctx, enemy);
+
+    let contributions = EnemyDamage::take_all(ctx, enemy.enemy_id);
+    let total_damage: u32 = contributions.iter().map(|entry| entry.damage).sum();
+    let reward = enemy.kind.stats().kill_reward;
+
+    let mut shared = 0;
+    for entry in contributions
+        .iter()
+        .filter(|entry| entry.attacker != killer)
+    {
+        let share = reward * entry.damage / total_damage.max(1);
+        award_coins(ctx, entry.attacker, enemy.scene_id, share);
+        shared += share;
+    }
+
+    award_coins(ctx, killer, enemy.scene_id, reward - shared);
+
+    let recipients: Vec<Identity> = contributions.iter().map(|entry| entry.attacker).collect();
+    let source = LootSource {
+        scene_id: enemy.scene_id,
+        position: enemy.position.clone(),
+    };
+    if let Err(e) = award_loot(ctx, enemy.kind.stats().loot_table, &recipients, &source) {
+        log::error!("Failed to award loot for enemy {}: {e}", enemy.enemy_id);
+    }
+
+    log::info!(
+        "Enemy {} ({}) killed by {}, {} coins shared between {} players",
+        enemy.enemy_id,
+        enemy.name,
+        killer,
+        reward,
+        contributions.len()
+    );
+}
+
+#[reducer]
+pub fn respawn_enemy(ctx: &ReducerContext, timer: EnemyRespawnTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer respawn_enemy may only be invoked by the scheduler".to_string());
+    }
+
+    let respawned_enemy = ctx.db.enemy().insert(Enemy::new(
+        timer.scene_id,
+        timer.name,
+        timer.kind,
+        timer.spawn_point,
+        timer.patrol,
+    ));
+
+    log::info!(
+        "Enemy {} ({:?}) respawned with id: {}",
+        respawned_enemy.name,
+        respawned_enemy.kind,
+        respawned_enemy.enemy_id
+    );
+
+    Ok(())
+}
+
+fn award_coins(ctx: &ReducerContext, identity: Identity, scene_id: u32, coins: u32) {
+    if coins == 0 {
+        return;
+    }
+
+    match ctx.db.player_score().player_identity().find(identity) {
+        Some(mut score) => {
+            score.add_coins(coins);
+            ctx.db.player_score().player_identity().update(score);
+        }
+        None => {
+            let mut score = PlayerScore::new(identity, scene_id);
+            score.add_coins(coins);
+            ctx.db.player_score().insert(score);
+        }
+    }
+}
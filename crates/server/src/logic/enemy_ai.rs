@@ -0,0 +1,132 @@
+use crate::elements::DbVector2;
+use crate::elements::character::{DbPlayer, player};
+use crate::elements::enemy::{
+    ENEMY_AI_INTERVAL, Enemy, EnemyAiTimer, EnemyState, EnemyStats, enemy,
+};
+
+use spacetimedb::{ReducerContext, Table, reducer};
+use std::collections::HashMap;
+
+#[reducer]
+pub fn update_enemies(ctx: &ReducerContext, _timer: EnemyAiTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer update_enemies may only be invoked by the scheduler".to_string());
+    }
+
+    let delta = ENEMY_AI_INTERVAL.as_secs_f32();
+    let mut empty_scenes = HashMap::new();
+
+    for enemy in ctx.db.enemy().iter() {
+        // Nobody can see or meet enemies in an empty scene, so they wait in place.
+        let scene_empty = *empty_scenes
+            .entry(enemy.scene_id)
+            .or_insert_with(|| DbPlayer::is_scene_empty(ctx, enemy.scene_id));
+        if scene_empty {
+            continue;
+        }
+
+        let updated = think(ctx, enemy.clone(), delta);
+
+        if updated.state != enemy.state {
+            log::trace!(
+                "Enemy {} ({}) switched from {:?} to {:?}",
+                updated.enemy_id,
+                updated.name,
+                enemy.state,
+                updated.state
+            );
+        }
+
+        if updated.position != enemy.position
+            || updated.state != enemy.state
+            || updated.direction != enemy.direction
+            || updated.patrol_step != enemy.patrol_step
+        {
+            ctx.db.enemy().enemy_id().update(updated);
+        }
+    }
+
+    Ok(())
+}
+
+fn think(ctx: &ReducerContext, mut enemy: Enemy, delta: f32) -> Enemy {
+    let stats = enemy.kind.stats();
+    enemy.state = choose_state(ctx, &enemy, &stats);
+
+    match enemy.state {
+        EnemyState::Chasing(identity) => {
+            let Some(target) = ctx.db.player().identity().find(identity) else {
+                return enemy;
+            };
+
+            let (min_x, max_x) = enemy.patrol_range();
+            let destination = DbVector2::new(
+                target.state.position.x.clamp(min_x, max_x),
+                enemy.position.y,
+            );
+            move_towards(&mut enemy, &destination, stats.chase_speed * delta);
+        }
+        EnemyState::Patrolling => {
+            let Some(destination) = enemy.patrol_target().cloned() else {
+                return enemy;
+            };
+
+            if move_towards(&mut enemy, &destination, stats.patrol_speed * delta) {
+                enemy.advance_patrol();
+            }
+        }
+    }
+
+    enemy
+}
+
+/// Keeps chasing a target that is still in leash range, otherwise picks the
+/// nearest online player inside aggro range.
+fn choose_state(ctx: &ReducerContext, enemy: &Enemy, stats: &EnemyStats) -> EnemyState {
+    if let EnemyState::Chasing(identity) = enemy.state
+        && let Some(target) = ctx.db.player().identity().find(identity)
+        && target.is_online()
+        && target.scene_id == enemy.scene_id
+        && target.state.position.distance_to(&enemy.position) <= stats.leash_range
+    {
+        return enemy.state;
+    }
+
+    ctx.db
+        .player()
+        .scene_id()
+        .filter(enemy.scene_id)
+        .filter(|player| player.is_online())
+        .map(|player| {
+            let distance = player.state.position.distance_to(&enemy.position);
+            (player.identity, distance)
+        })
+        .filter(|(_, distance)| *distance <= stats.aggro_range)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(EnemyState::Patrolling, |(identity, _)| {
+            EnemyState::Chasing(identity)
+        })
+}
+
+/// Steps `enemy` at most `max_step` towards `destination`, returning whether it arrived.
+fn move_towards(enemy: &mut Enemy, destination: &DbVector2, max_step: f32) -> bool {
+    let offset_x = destination.x - enemy.position.x;
+    let offset_y = destination.y - enemy.position.y;
+    let distance = offset_x.hypot(offset_y);
+
+    if offset_x.abs() > f32::EPSILON {
+        enemy.direction = offset_x.signum() as i32;
+    }
+
+    if distance <= max_step {
+        enemy.position = destination.clone();
+        return true;
+    }
+
+    enemy.position = DbVector2::new(
+        enemy.position.x + offset_x / distance * max_step,
+        enemy.position.y + offset_y / distance * max_step,
+    );
+
+    false
+}
@@ -0,0 +1,155 @@
+use crate::elements::character::{DbPlayer, DeathReport, player};
+use crate::elements::checkpoint::Checkpoint;
+use crate::elements::enemy::enemy;
+use crate::elements::movement::MovementTracker;
+use crate::elements::server_config::ServerConfig;
+use crate::elements::world_scene::{kill_zone, world_scene};
+
+use spacetimedb::{ReducerContext, SpacetimeType, reducer};
+
+/// How close a player has to be to an enemy for its touch to count.
+const ENEMY_CONTACT_RANGE: f32 = 24.0;
+
+/// Something a client reports its own player ran into.
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hazard {
+    /// Falling out of the level; always fatal.
+    KillZone,
+    Enemy(u64),
+}
+
+/// Clients only ever report hazards that hurt themselves, so the server
+/// checks the report is plausible and leaves the rest to `take_damage`.
+#[reducer]
+pub fn report_hazard(ctx: &ReducerContext, hazard: Hazard) -> Result<(), String> {
+    log::trace!("Player {} reported hazard {:?}", ctx.sender, hazard);
+
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not registered")?;
+
+    if !player.is_online() {
+        return Err("Player is not online".to_string());
+    }
+
+    let damage = match hazard {
+        Hazard::KillZone => {
+            let config = ServerConfig::get(ctx);
+            let kill_zone = ctx
+                .db
+                .kill_zone()
+                .scene_id()
+                .find(player.scene_id)
+                .ok_or("Scene has no kill zone")?;
+
+            // Positions grow downwards, so a fall ends below the boundary.
+            let height_above = kill_zone.y - player.state.position.y;
+            if height_above > config.kill_zone_tolerance {
+                return Err(format!("Player is {height_above:.1} above the kill zone"));
+            }
+
+            // Clients keep reporting while inside the kill zone, until the
+            // respawn reaches them.
+            if !DeathReport::try_record(ctx, ctx.sender, config.death_report_cooldown) {
+                log::trace!("Player {} reported a death too soon", player.name);
+                return Ok(());
+            }
+
+            player.health
+        }
+        Hazard::Enemy(enemy_id) => {
+            let enemy = ctx
+                .db
+                .enemy()
+                .enemy_id()
+                .find(enemy_id)
+                .ok_or(format!("Enemy {enemy_id} not found"))?;
+
+            if enemy.scene_id != player.scene_id {
+                return Err(format!("Enemy {enemy_id} is in another scene"));
+            }
+
+            let distance = player.state.position.distance_to(&enemy.position);
+            if distance > ENEMY_CONTACT_RANGE {
+                return Err(format!(
+                    "Enemy {enemy_id} is out of reach ({distance:.1} away)"
+                ));
+            }
+
+            // An enemy that was just stomped is the one being hurt.
+            if enemy.is_invulnerable(ctx.timestamp) {
+                return Ok(());
+            }
+
+            enemy.kind.stats().contact_damage
+        }
+    };
+
+    take_damage(ctx, player, hazard, damage)
+}
+
+/// Applies `amount` damage from `hazard` unless the player is invulnerable.
+/// Kill zones ignore invulnerability, as a player falling through one would
+/// otherwise never land. A fatal hit counts a death and respawns the player
+/// at its last checkpoint, or at the scene's spawn point if it has not
+/// activated one.
+pub fn take_damage(
+    ctx: &ReducerContext,
+    mut player: DbPlayer,
+    hazard: Hazard,
+    amount: u32,
+) -> Result<(), String> {
+    if hazard != Hazard::KillZone && player.is_invulnerable(ctx.timestamp) {
+        log::trace!("Player {} is invulnerable, damage ignored", player.name);
+        return Ok(());
+    }
+
+    let config = ServerConfig::get(ctx);
+    player.health = player.health.saturating_sub(amount);
+
+    if player.health > 0 {
+        player.invulnerable_until = Some(ctx.timestamp + config.hit_invulnerability);
+        let player = ctx.db.player().identity().update(player);
+
+        log::info!(
+            "Player {} took {} damage, {} health left",
+            player.name,
+            amount,
+            player.health
+        );
+
+        return Ok(());
+    }
+
+    let scene = ctx
+        .db
+        .world_scene()
+        .scene_id()
+        .find(player.scene_id)
+        .ok_or("Scene does not exist")?;
+
+    let respawn_point = Checkpoint::last_activated(ctx, &player)
+        .map_or(scene.spawn_point, |checkpoint| checkpoint.position);
+
+    player.deaths += 1;
+    player.health = config.player_max_health;
+    player.state.teleport(respawn_point);
+    player.state_updated_at = ctx.timestamp;
+    player.invulnerable_until = Some(ctx.timestamp + config.respawn_invulnerability);
+    let player = ctx.db.player().identity().update(player);
+
+    MovementTracker::settle(ctx, player.identity, config.movement_settle_period);
+
+    log::info!(
+        "Player {} died ({} deaths) and respawned in {} at {}",
+        player.name,
+        player.deaths,
+        scene.name,
+        player.state.position
+    );
+
+    Ok(())
+}
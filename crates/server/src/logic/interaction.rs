@@ -1,40 +1,89 @@
-use crate::elements::DbVector2;
 use crate::elements::character::player;
+use crate::elements::checkpoint::{Checkpoint, checkpoint};
+use crate::elements::chest::{Chest, ChestOpening, chest, chest_opening};
+use crate::elements::coin::CoinRespawnTimer;
 use crate::elements::player_score::PlayerScore;
+use crate::elements::world_scene::{SceneCompletion, WorldScene, scene_completion, world_scene};
 use crate::elements::{coin::coin, player_score::player_score};
+use crate::logic::loot::{LootSource, award_loot};
 
 use spacetimedb::{ReducerContext, Table, reducer};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CollectCoinError {
+    PlayerNotRegistered,
+    PlayerNotOnline,
+    CoinNotFound(u64),
+    WrongScene(u64),
+    AlreadyCollected(u64),
+    TooFarAway { coin_id: u64, distance: f32 },
+    SceneNotFound(u32),
+}
+
+impl fmt::Display for CollectCoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlayerNotRegistered => write!(f, "Player not registered"),
+            Self::PlayerNotOnline => write!(f, "Player is not online"),
+            Self::CoinNotFound(coin_id) => write!(f, "Coin {coin_id} not found"),
+            Self::WrongScene(coin_id) => write!(f, "Coin {coin_id} is in another scene"),
+            Self::AlreadyCollected(coin_id) => write!(f, "Coin {coin_id} already collected"),
+            Self::TooFarAway { coin_id, distance } => {
+                write!(f, "Coin {coin_id} is out of reach ({distance:.1} away)")
+            }
+            Self::SceneNotFound(scene_id) => write!(f, "World scene {scene_id} not found"),
+        }
+    }
+}
 
 #[reducer]
-pub fn try_collect_coin(ctx: &ReducerContext, position: DbVector2) -> Result<(), String> {
-    log::trace!(
-        "Player {} is collecting a coin at position ({}, {})",
-        ctx.sender,
-        position.x,
-        position.y
-    );
+pub fn try_collect_coin(ctx: &ReducerContext, coin_id: u64) -> Result<(), CollectCoinError> {
+    log::trace!("Player {} is collecting coin {}", ctx.sender, coin_id);
 
     let player = ctx
         .db
         .player()
         .identity()
         .find(ctx.sender)
-        .ok_or("Player not registered")?;
+        .ok_or(CollectCoinError::PlayerNotRegistered)?;
+
+    if !player.is_online() {
+        return Err(CollectCoinError::PlayerNotOnline);
+    }
 
     let mut coin = ctx
         .db
         .coin()
-        .iter()
-        .find(|coin| coin.position.x == position.x && coin.position.y == position.y)
-        .ok_or("Coin not found at this position")?;
+        .coin_id()
+        .find(coin_id)
+        .ok_or(CollectCoinError::CoinNotFound(coin_id))?;
+
+    if coin.scene_id != player.scene_id {
+        return Err(CollectCoinError::WrongScene(coin_id));
+    }
 
     if coin.collected_by.is_some() {
-        return Err("Coin already collected".to_string());
+        return Err(CollectCoinError::AlreadyCollected(coin_id));
+    }
+
+    let world_scene = ctx
+        .db
+        .world_scene()
+        .scene_id()
+        .find(coin.scene_id)
+        .ok_or(CollectCoinError::SceneNotFound(coin.scene_id))?;
+
+    let distance = player.state.position.distance_to(&coin.position);
+    if distance > world_scene.coin_pickup_radius {
+        return Err(CollectCoinError::TooFarAway { coin_id, distance });
     }
 
     coin.collected_by = Some(ctx.sender);
     let updated_coin = ctx.db.coin().coin_id().update(coin);
 
+    CoinRespawnTimer::schedule(ctx, updated_coin.coin_id, world_scene.coin_respawn_delay);
+
     if let Some(mut score) = ctx.db.player_score().player_identity().find(ctx.sender) {
         score.add_coin();
         let updated_score = ctx.db.player_score().player_identity().update(score);
@@ -65,5 +114,319 @@ pub fn try_collect_coin(ctx: &ReducerContext, position: DbVector2) -> Result<(),
         );
     }
 
+    if let Some(loot_table) = &world_scene.coin_loot_table {
+        let source = LootSource {
+            scene_id: updated_coin.scene_id,
+            position: updated_coin.position.clone(),
+        };
+
+        if let Err(e) = award_loot(ctx, loot_table, &[ctx.sender], &source) {
+            log::error!(
+                "Failed to award loot for coin {}: {e}",
+                updated_coin.coin_id
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActivateCheckpointError {
+    PlayerNotRegistered,
+    PlayerNotOnline,
+    CheckpointNotFound(u64),
+    WrongScene(u64),
+    TooFarAway { checkpoint_id: u64, distance: f32 },
+}
+
+impl fmt::Display for ActivateCheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlayerNotRegistered => write!(f, "Player not registered"),
+            Self::PlayerNotOnline => write!(f, "Player is not online"),
+            Self::CheckpointNotFound(checkpoint_id) => {
+                write!(f, "Checkpoint {checkpoint_id} not found")
+            }
+            Self::WrongScene(checkpoint_id) => {
+                write!(f, "Checkpoint {checkpoint_id} is in another scene")
+            }
+            Self::TooFarAway {
+                checkpoint_id,
+                distance,
+            } => write!(
+                f,
+                "Checkpoint {checkpoint_id} is out of reach ({distance:.1} away)"
+            ),
+        }
+    }
+}
+
+/// Makes `checkpoint_id` the caller's respawn point. Walking through an
+/// already active checkpoint again changes nothing.
+#[reducer]
+pub fn activate_checkpoint(
+    ctx: &ReducerContext,
+    checkpoint_id: u64,
+) -> Result<(), ActivateCheckpointError> {
+    log::trace!(
+        "Player {} is activating checkpoint {}",
+        ctx.sender,
+        checkpoint_id
+    );
+
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or(ActivateCheckpointError::PlayerNotRegistered)?;
+
+    if !player.is_online() {
+        return Err(ActivateCheckpointError::PlayerNotOnline);
+    }
+
+    let checkpoint = ctx
+        .db
+        .checkpoint()
+        .checkpoint_id()
+        .find(checkpoint_id)
+        .ok_or(ActivateCheckpointError::CheckpointNotFound(checkpoint_id))?;
+
+    if checkpoint.scene_id != player.scene_id {
+        return Err(ActivateCheckpointError::WrongScene(checkpoint_id));
+    }
+
+    let distance = player.state.position.distance_to(&checkpoint.position);
+    if distance > Checkpoint::ACTIVATION_RADIUS {
+        return Err(ActivateCheckpointError::TooFarAway {
+            checkpoint_id,
+            distance,
+        });
+    }
+
+    if player.checkpoint_id == Some(checkpoint_id) {
+        return Ok(());
+    }
+
+    player.checkpoint_id = Some(checkpoint_id);
+    let player = ctx.db.player().identity().update(player);
+
+    log::info!(
+        "Player {} ({}) activated checkpoint {}",
+        player.name,
+        player.identity,
+        checkpoint.name
+    );
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpenChestError {
+    PlayerNotRegistered,
+    PlayerNotOnline,
+    ChestNotFound(u64),
+    WrongScene(u64),
+    TooFarAway { chest_id: u64, distance: f32 },
+    AlreadyOpened(u64),
+    Loot(String),
+}
+
+impl fmt::Display for OpenChestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlayerNotRegistered => write!(f, "Player not registered"),
+            Self::PlayerNotOnline => write!(f, "Player is not online"),
+            Self::ChestNotFound(chest_id) => write!(f, "Chest {chest_id} not found"),
+            Self::WrongScene(chest_id) => write!(f, "Chest {chest_id} is in another scene"),
+            Self::TooFarAway { chest_id, distance } => {
+                write!(f, "Chest {chest_id} is out of reach ({distance:.1} away)")
+            }
+            Self::AlreadyOpened(chest_id) => write!(f, "Chest {chest_id} already opened"),
+            Self::Loot(e) => write!(f, "Failed to award loot: {e}"),
+        }
+    }
+}
+
+/// Opens `chest_id` for the caller and evaluates its loot table. Every
+/// player gets to open each chest once.
+#[reducer]
+pub fn open_chest(ctx: &ReducerContext, chest_id: u64) -> Result<(), OpenChestError> {
+    log::trace!("Player {} is opening chest {}", ctx.sender, chest_id);
+
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or(OpenChestError::PlayerNotRegistered)?;
+
+    if !player.is_online() {
+        return Err(OpenChestError::PlayerNotOnline);
+    }
+
+    let chest = ctx
+        .db
+        .chest()
+        .chest_id()
+        .find(chest_id)
+        .ok_or(OpenChestError::ChestNotFound(chest_id))?;
+
+    if chest.scene_id != player.scene_id {
+        return Err(OpenChestError::WrongScene(chest_id));
+    }
+
+    let distance = player.state.position.distance_to(&chest.position);
+    if distance > Chest::OPEN_RADIUS {
+        return Err(OpenChestError::TooFarAway { chest_id, distance });
+    }
+
+    if chest.is_opened_by(ctx, ctx.sender) {
+        return Err(OpenChestError::AlreadyOpened(chest_id));
+    }
+
+    ctx.db.chest_opening().insert(ChestOpening {
+        opening_id: 0,
+        chest_id,
+        opened_by: ctx.sender,
+        opened_at: ctx.timestamp,
+    });
+
+    let source = LootSource {
+        scene_id: chest.scene_id,
+        position: chest.position.clone(),
+    };
+    award_loot(ctx, &chest.loot_table, &[ctx.sender], &source).map_err(OpenChestError::Loot)?;
+
+    log::info!(
+        "Player {} ({}) opened chest {}",
+        player.name,
+        player.identity,
+        chest.name
+    );
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompleteSceneError {
+    PlayerNotRegistered,
+    PlayerNotOnline,
+    SceneNotFound(u32),
+    NoGoal(u32),
+    TooFarAway { scene_id: u32, distance: f32 },
+    AlreadyCompleted(u32),
+    Loot(String),
+}
+
+impl fmt::Display for CompleteSceneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlayerNotRegistered => write!(f, "Player not registered"),
+            Self::PlayerNotOnline => write!(f, "Player is not online"),
+            Self::SceneNotFound(scene_id) => write!(f, "World scene {scene_id} not found"),
+            Self::NoGoal(scene_id) => write!(f, "World scene {scene_id} has no goal"),
+            Self::TooFarAway { scene_id, distance } => write!(
+                f,
+                "Goal of scene {scene_id} is out of reach ({distance:.1} away)"
+            ),
+            Self::AlreadyCompleted(scene_id) => {
+                write!(f, "World scene {scene_id} already completed")
+            }
+            Self::Loot(e) => write!(f, "Failed to award loot: {e}"),
+        }
+    }
+}
+
+/// Completes the caller's current scene once they reach its goal. Only the
+/// first completion is recorded and rewarded.
+#[reducer]
+pub fn complete_scene(ctx: &ReducerContext) -> Result<(), CompleteSceneError> {
+    log::trace!("Player {} is completing their scene", ctx.sender);
+
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or(CompleteSceneError::PlayerNotRegistered)?;
+
+    if !player.is_online() {
+        return Err(CompleteSceneError::PlayerNotOnline);
+    }
+
+    let world_scene = ctx
+        .db
+        .world_scene()
+        .scene_id()
+        .find(player.scene_id)
+        .ok_or(CompleteSceneError::SceneNotFound(player.scene_id))?;
+
+    let goal = world_scene
+        .goal
+        .as_ref()
+        .ok_or(CompleteSceneError::NoGoal(world_scene.scene_id))?;
+
+    let distance = player.state.position.distance_to(goal);
+    if distance > WorldScene::GOAL_RADIUS {
+        return Err(CompleteSceneError::TooFarAway {
+            scene_id: world_scene.scene_id,
+            distance,
+        });
+    }
+
+    if SceneCompletion::exists(ctx, ctx.sender, world_scene.scene_id) {
+        return Err(CompleteSceneError::AlreadyCompleted(world_scene.scene_id));
+    }
+
+    ctx.db.scene_completion().insert(SceneCompletion {
+        completion_id: 0,
+        identity: ctx.sender,
+        scene_id: world_scene.scene_id,
+        completed_at: ctx.timestamp,
+    });
+
+    if let Some(loot_table) = &world_scene.completion_loot_table {
+        let source = LootSource {
+            scene_id: world_scene.scene_id,
+            position: goal.clone(),
+        };
+        award_loot(ctx, loot_table, &[ctx.sender], &source).map_err(CompleteSceneError::Loot)?;
+    }
+
+    log::info!(
+        "Player {} ({}) completed scene {}",
+        player.name,
+        player.identity,
+        world_scene.name
+    );
+
+    Ok(())
+}
+
+#[reducer]
+pub fn respawn_coin(ctx: &ReducerContext, timer: CoinRespawnTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer respawn_coin may only be invoked by the scheduler".to_string());
+    }
+
+    let mut coin = ctx
+        .db
+        .coin()
+        .coin_id()
+        .find(timer.coin_id)
+        .ok_or("Coin not found")?;
+
+    coin.collected_by = None;
+    let respawned_coin = ctx.db.coin().coin_id().update(coin);
+
+    log::info!(
+        "Coin {} respawned at ({}, {})",
+        respawned_coin.coin_id,
+        respawned_coin.position.x,
+        respawned_coin.position.y
+    );
+
     Ok(())
 }
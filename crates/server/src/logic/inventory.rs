@@ -0,0 +1,329 @@
+use crate::elements::character::{DbPlayer, player};
+use crate::elements::inventory::{DroppedItem, InventoryItem, dropped_item, inventory};
+use crate::elements::item::{ItemDefinition, item_definition};
+use crate::elements::server_config::ServerConfig;
+
+use spacetimedb::{Identity, ReducerContext, Table, reducer};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InventoryError {
+    PlayerNotRegistered,
+    PlayerNotOnline,
+    NotAuthorized,
+    UnknownItem(String),
+    ItemNotFound(u32),
+    InvalidQuantity(u32),
+    InventoryFull,
+    StackNotFound(u64),
+    NotEquippable(u64),
+    AlreadyEquipped(u64),
+    NotEquipped(u64),
+    NoFreeArtifactSlot,
+    DropNotFound(u64),
+    WrongScene(u64),
+    TooFarAway { drop_id: u64, distance: f32 },
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PlayerNotRegistered => write!(f, "Player not registered"),
+            Self::PlayerNotOnline => write!(f, "Player is not online"),
+            Self::NotAuthorized => write!(f, "Only the admin may grant items"),
+            Self::UnknownItem(name) => write!(f, "Item {name} does not exist"),
+            Self::ItemNotFound(item_id) => write!(f, "Item {item_id} does not exist"),
+            Self::InvalidQuantity(quantity) => write!(f, "Invalid quantity {quantity}"),
+            Self::InventoryFull => write!(f, "Inventory is full"),
+            Self::StackNotFound(inventory_id) => {
+                write!(f, "Inventory stack {inventory_id} not found")
+            }
+            Self::NotEquippable(inventory_id) => {
+                write!(f, "Inventory stack {inventory_id} is not an artifact")
+            }
+            Self::AlreadyEquipped(inventory_id) => {
+                write!(f, "Inventory stack {inventory_id} is already equipped")
+            }
+            Self::NotEquipped(inventory_id) => {
+                write!(f, "Inventory stack {inventory_id} is not equipped")
+            }
+            Self::NoFreeArtifactSlot => write!(f, "No free artifact slot"),
+            Self::DropNotFound(drop_id) => write!(f, "Dropped item {drop_id} not found"),
+            Self::WrongScene(drop_id) => write!(f, "Dropped item {drop_id} is in another scene"),
+            Self::TooFarAway { drop_id, distance } => {
+                write!(
+                    f,
+                    "Dropped item {drop_id} is out of reach ({distance:.1} away)"
+                )
+            }
+        }
+    }
+}
+
+/// Gives `quantity` units of an item to any player. Only the admin may call this.
+#[reducer]
+pub fn grant_item(
+    ctx: &ReducerContext,
+    owner: Identity,
+    item_name: String,
+    quantity: u32,
+) -> Result<(), InventoryError> {
+    log::trace!(
+        "{} is granting {} x{} to {}",
+        ctx.sender,
+        item_name,
+        quantity,
+        owner
+    );
+
+    if !ServerConfig::get(ctx).is_admin(ctx.sender) {
+        return Err(InventoryError::NotAuthorized);
+    }
+
+    if ctx.db.player().identity().find(owner).is_none() {
+        return Err(InventoryError::PlayerNotRegistered);
+    }
+
+    let item = ctx
+        .db
+        .item_definition()
+        .name()
+        .find(&item_name)
+        .ok_or(InventoryError::UnknownItem(item_name))?;
+
+    give_items(ctx, owner, &item, quantity)?;
+
+    log::info!("Granted {} x{} to {}", item.name, quantity, owner);
+
+    Ok(())
+}
+
+/// Moves `quantity` units out of a stack onto the ground at the player's feet.
+/// Dropping an equipped artifact unequips it.
+#[reducer]
+pub fn drop_item(
+    ctx: &ReducerContext,
+    inventory_id: u64,
+    quantity: u32,
+) -> Result<(), InventoryError> {
+    log::trace!(
+        "Player {} is dropping {} from stack {}",
+        ctx.sender,
+        quantity,
+        inventory_id
+    );
+
+    let player = online_player(ctx)?;
+    let mut stack = owned_stack(ctx, inventory_id)?;
+
+    if quantity == 0 || quantity > stack.quantity {
+        return Err(InventoryError::InvalidQuantity(quantity));
+    }
+
+    stack.quantity -= quantity;
+    let item_id = stack.item_id;
+    if stack.quantity == 0 {
+        ctx.db.inventory().inventory_id().delete(inventory_id);
+    } else {
+        ctx.db.inventory().inventory_id().update(stack);
+    }
+
+    let dropped = ctx.db.dropped_item().insert(DroppedItem {
+        drop_id: 0,
+        scene_id: player.scene_id,
+        item_id,
+        quantity,
+        position: player.state.position.clone(),
+    });
+
+    log::info!(
+        "Player {} dropped item {} x{} at {}",
+        player.name,
+        dropped.item_id,
+        dropped.quantity,
+        dropped.position
+    );
+
+    Ok(())
+}
+
+#[reducer]
+pub fn pick_up_item(ctx: &ReducerContext, drop_id: u64) -> Result<(), InventoryError> {
+    log::trace!("Player {} is picking up item {}", ctx.sender, drop_id);
+
+    let player = online_player(ctx)?;
+
+    let dropped = ctx
+        .db
+        .dropped_item()
+        .drop_id()
+        .find(drop_id)
+        .ok_or(InventoryError::DropNotFound(drop_id))?;
+
+    if dropped.scene_id != player.scene_id {
+        return Err(InventoryError::WrongScene(drop_id));
+    }
+
+    let distance = player.state.position.distance_to(&dropped.position);
+    if distance > DroppedItem::PICKUP_RADIUS {
+        return Err(InventoryError::TooFarAway { drop_id, distance });
+    }
+
+    let item = find_item(ctx, dropped.item_id)?;
+    give_items(ctx, player.identity, &item, dropped.quantity)?;
+    ctx.db.dropped_item().drop_id().delete(drop_id);
+
+    log::info!(
+        "Player {} picked up {} x{}",
+        player.name,
+        item.name,
+        dropped.quantity
+    );
+
+    Ok(())
+}
+
+#[reducer]
+pub fn equip_item(ctx: &ReducerContext, inventory_id: u64) -> Result<(), InventoryError> {
+    log::trace!("Player {} is equipping stack {}", ctx.sender, inventory_id);
+
+    let player = online_player(ctx)?;
+    let mut stack = owned_stack(ctx, inventory_id)?;
+
+    if !find_item(ctx, stack.item_id)?.is_equippable() {
+        return Err(InventoryError::NotEquippable(inventory_id));
+    }
+
+    if stack.equipped {
+        return Err(InventoryError::AlreadyEquipped(inventory_id));
+    }
+
+    let equipped_count = InventoryItem::of(ctx, ctx.sender)
+        .filter(|other| other.equipped)
+        .count() as u32;
+    if equipped_count >= ServerConfig::get(ctx).artifact_slots {
+        return Err(InventoryError::NoFreeArtifactSlot);
+    }
+
+    stack.equipped = true;
+    ctx.db.inventory().inventory_id().update(stack);
+
+    log::info!("Player {} equipped stack {}", player.name, inventory_id);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn unequip_item(ctx: &ReducerContext, inventory_id: u64) -> Result<(), InventoryError> {
+    log::trace!(
+        "Player {} is unequipping stack {}",
+        ctx.sender,
+        inventory_id
+    );
+
+    let player = online_player(ctx)?;
+    let mut stack = owned_stack(ctx, inventory_id)?;
+
+    if !stack.equipped {
+        return Err(InventoryError::NotEquipped(inventory_id));
+    }
+
+    stack.equipped = false;
+    ctx.db.inventory().inventory_id().update(stack);
+
+    log::info!("Player {} unequipped stack {}", player.name, inventory_id);
+
+    Ok(())
+}
+
+/// Adds `quantity` units of `item` to `owner`'s inventory, topping up
+/// existing stacks before opening new ones. Fails without changing anything
+/// if the inventory has too few free slots.
+pub fn give_items(
+    ctx: &ReducerContext,
+    owner: Identity,
+    item: &ItemDefinition,
+    quantity: u32,
+) -> Result<(), InventoryError> {
+    if quantity == 0 {
+        return Err(InventoryError::InvalidQuantity(quantity));
+    }
+
+    let stacks: Vec<InventoryItem> = InventoryItem::of(ctx, owner).collect();
+    let open_stacks: Vec<InventoryItem> = stacks
+        .iter()
+        .filter(|stack| {
+            stack.item_id == item.item_id && !stack.equipped && stack.quantity < item.max_stack
+        })
+        .cloned()
+        .collect();
+
+    let room: u32 = open_stacks
+        .iter()
+        .map(|stack| item.max_stack - stack.quantity)
+        .sum();
+    let new_stacks = quantity.saturating_sub(room).div_ceil(item.max_stack);
+
+    if stacks.len() as u32 + new_stacks > ServerConfig::get(ctx).inventory_slots {
+        return Err(InventoryError::InventoryFull);
+    }
+
+    let mut remaining = quantity;
+    for mut stack in open_stacks {
+        if remaining == 0 {
+            break;
+        }
+
+        let added = remaining.min(item.max_stack - stack.quantity);
+        stack.quantity += added;
+        remaining -= added;
+        ctx.db.inventory().inventory_id().update(stack);
+    }
+
+    while remaining > 0 {
+        let added = remaining.min(item.max_stack);
+        ctx.db.inventory().insert(InventoryItem {
+            inventory_id: 0,
+            owner,
+            item_id: item.item_id,
+            quantity: added,
+            equipped: false,
+        });
+        remaining -= added;
+    }
+
+    Ok(())
+}
+
+fn online_player(ctx: &ReducerContext) -> Result<DbPlayer, InventoryError> {
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or(InventoryError::PlayerNotRegistered)?;
+
+    if !player.is_online() {
+        return Err(InventoryError::PlayerNotOnline);
+    }
+
+    Ok(player)
+}
+
+/// A stack of the caller's; other players' stacks are reported as missing.
+fn owned_stack(ctx: &ReducerContext, inventory_id: u64) -> Result<InventoryItem, InventoryError> {
+    ctx.db
+        .inventory()
+        .inventory_id()
+        .find(inventory_id)
+        .filter(|stack| stack.owner == ctx.sender)
+        .ok_or(InventoryError::StackNotFound(inventory_id))
+}
+
+fn find_item(ctx: &ReducerContext, item_id: u32) -> Result<ItemDefinition, InventoryError> {
+    ctx.db
+        .item_definition()
+        .item_id()
+        .find(item_id)
+        .ok_or(InventoryError::ItemNotFound(item_id))
+}
@@ -0,0 +1,88 @@
+use crate::elements::DbVector2;
+use crate::elements::inventory::{DroppedItem, dropped_item};
+use crate::elements::item::item_definition;
+use crate::elements::loot::{LootDistribution, LootDrop, LootTable};
+use crate::logic::inventory::{InventoryError, give_items};
+
+use spacetimedb::{Identity, ReducerContext, Table};
+
+/// Where a loot table was evaluated, for the world drops it produces.
+pub struct LootSource {
+    pub scene_id: u32,
+    pub position: DbVector2,
+}
+
+/// Evaluates `table_name` for `recipients`. Per-player tables roll for each
+/// recipient and go straight into their inventory; shared tables roll once
+/// and drop the loot at the source for anyone to pick up. Loot that does not
+/// fit into an inventory is dropped at the source as well.
+pub fn award_loot(
+    ctx: &ReducerContext,
+    table_name: &str,
+    recipients: &[Identity],
+    source: &LootSource,
+) -> Result<(), String> {
+    let table = LootTable::find(ctx, table_name)?;
+    let mut rng = ctx.rng();
+
+    match table.distribution {
+        LootDistribution::Shared => {
+            for drop in table.roll(&mut rng) {
+                drop_in_world(ctx, &drop, source);
+            }
+
+            log::info!("Loot table {} dropped shared loot", table.name);
+        }
+        LootDistribution::PerPlayer => {
+            for recipient in recipients {
+                for drop in table.roll(&mut rng) {
+                    give_or_drop(ctx, *recipient, &drop, source)?;
+                }
+            }
+
+            log::info!(
+                "Loot table {} rolled for {} players",
+                table.name,
+                recipients.len()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn give_or_drop(
+    ctx: &ReducerContext,
+    recipient: Identity,
+    drop: &LootDrop,
+    source: &LootSource,
+) -> Result<(), String> {
+    let item = ctx
+        .db
+        .item_definition()
+        .item_id()
+        .find(drop.item_id)
+        .ok_or(format!("Item {} does not exist", drop.item_id))?;
+
+    match give_items(ctx, recipient, &item, drop.quantity) {
+        Ok(()) => {
+            log::info!("{} received {} x{}", recipient, item.name, drop.quantity);
+            Ok(())
+        }
+        Err(InventoryError::InventoryFull) => {
+            drop_in_world(ctx, drop, source);
+            Ok(())
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn drop_in_world(ctx: &ReducerContext, drop: &LootDrop, source: &LootSource) {
+    ctx.db.dropped_item().insert(DroppedItem {
+        drop_id: 0,
+        scene_id: source.scene_id,
+        item_id: drop.item_id,
+        quantity: drop.quantity,
+        position: source.position.clone(),
+    });
+}
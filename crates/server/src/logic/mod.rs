@@ -1,2 +1,7 @@
+pub mod combat;
+pub mod enemy_ai;
+pub mod health;
 pub mod interaction;
+pub mod inventory;
+pub mod loot;
 pub mod movement;
@@ -1,7 +1,20 @@
+use crate::elements::DbVector2;
 use crate::elements::character::{DBPlayerState, player};
+use crate::elements::inventory::InventoryItem;
+use crate::elements::movement::{
+    MovementSuspicion, MovementTracker, movement_limits, movement_suspicion, movement_tracker,
+};
+use crate::elements::server_config::ServerConfig;
 
-use spacetimedb::{ReducerContext, reducer};
+use game_rules::movement::{Move, MovementViolation};
+use spacetimedb::{ReducerContext, Table, Timestamp, reducer};
 
+/// Stores the state the client reports for its player. Every update is
+/// checked against the last accepted one and the time since; implausible
+/// moves are clamped to what the player could have done and count as a
+/// strike against it. The stored state echoes the input sequence back, so
+/// the client can replay its inputs on top of a clamped position. Updates
+/// beyond the per-player rate limit are rejected.
 #[reducer]
 pub fn send_player_state(ctx: &ReducerContext, state: DBPlayerState) -> Result<(), String> {
     let mut player = ctx
@@ -11,7 +24,50 @@ pub fn send_player_state(ctx: &ReducerContext, state: DBPlayerState) -> Result<(
         .find(ctx.sender)
         .ok_or("Player not registered")?;
 
+    // Updates may arrive out of order; anything older than the last accepted input is stale.
+    if state.input_sequence <= player.state.input_sequence {
+        log::trace!(
+            "Ignored stale input {} from player {}",
+            state.input_sequence,
+            ctx.sender
+        );
+
+        return Ok(());
+    }
+
+    let config = ServerConfig::get(ctx);
+    let mut tracker = ctx
+        .db
+        .movement_tracker()
+        .identity()
+        .find(ctx.sender)
+        .unwrap_or_else(|| MovementTracker::new(ctx.sender, ctx.timestamp));
+
+    if !tracker.admit_update(
+        ctx.timestamp,
+        config.state_update_rate,
+        config.state_update_burst,
+    ) {
+        return Err("Too many state updates".to_string());
+    }
+
+    let (state, violations) = check_movement(ctx, &config, &mut tracker, &player.state, state);
+    if violations.is_empty() {
+        tracker.settle_until = None;
+    } else if tracker.is_settling(ctx.timestamp) {
+        log::trace!(
+            "Dropped update from player {} sent before it was moved",
+            ctx.sender
+        );
+        return Ok(());
+    } else {
+        record_strike(ctx, &config, &mut tracker, &violations);
+    }
+
+    tracker.save(ctx);
+
     player.state = state;
+    player.state_updated_at = ctx.timestamp;
 
     let _player = ctx.db.player().identity().update(player);
 
@@ -23,3 +79,108 @@ pub fn send_player_state(ctx: &ReducerContext, state: DBPlayerState) -> Result<(
 
     Ok(())
 }
+
+/// Returns `state` with anything beyond the player's movement limits clamped
+/// away, along with what had to be clamped.
+fn check_movement(
+    ctx: &ReducerContext,
+    config: &ServerConfig,
+    tracker: &mut MovementTracker,
+    previous: &DBPlayerState,
+    mut state: DBPlayerState,
+) -> (DBPlayerState, Vec<MovementViolation>) {
+    let limits = movement_limits(&InventoryItem::equipped_effects(ctx, ctx.sender));
+
+    let checked = limits.check(
+        &config.movement_allowance(),
+        &Move {
+            from: (previous.position.x, previous.position.y),
+            to: (state.position.x, state.position.y),
+            elapsed: elapsed_seconds(tracker.last_update_at, ctx.timestamp),
+            is_jumping: state.is_jumping,
+            jump_origin_y: tracker.jump_origin_y,
+        },
+    );
+
+    let (x, y) = checked.position;
+    state.position = DbVector2::new(x, y);
+
+    tracker.last_update_at = ctx.timestamp;
+    tracker.jump_origin_y = checked.jump_origin_y;
+
+    (state, checked.violations)
+}
+
+/// Counts a strike against the player. Running out of strikes within the
+/// window records the player in `movement_suspicion` and starts over.
+fn record_strike(
+    ctx: &ReducerContext,
+    config: &ServerConfig,
+    tracker: &mut MovementTracker,
+    violations: &[MovementViolation],
+) {
+    let violation = violations
+        .iter()
+        .map(|violation| violation.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let window_expired = tracker
+        .last_strike_at
+        .is_some_and(|at| at + config.movement_strike_window < ctx.timestamp);
+    if window_expired {
+        tracker.strikes = 0;
+    }
+
+    tracker.strikes += 1;
+    tracker.last_strike_at = Some(ctx.timestamp);
+
+    log::warn!(
+        "Player {} sent implausible movement ({} strikes): {}",
+        tracker.identity,
+        tracker.strikes,
+        violation
+    );
+
+    if tracker.strikes < config.movement_strike_limit {
+        return;
+    }
+
+    tracker.strikes = 0;
+
+    match ctx
+        .db
+        .movement_suspicion()
+        .identity()
+        .find(tracker.identity)
+    {
+        Some(mut suspicion) => {
+            suspicion.offences += 1;
+            suspicion.last_offence_at = ctx.timestamp;
+            suspicion.last_violation = violation;
+            let suspicion = ctx.db.movement_suspicion().identity().update(suspicion);
+
+            log::warn!(
+                "Player {} is suspicious of cheating ({} offences)",
+                suspicion.identity,
+                suspicion.offences
+            );
+        }
+        None => {
+            ctx.db.movement_suspicion().insert(MovementSuspicion {
+                identity: tracker.identity,
+                offences: 1,
+                last_offence_at: ctx.timestamp,
+                last_violation: violation,
+            });
+
+            log::warn!("Player {} is suspicious of cheating", tracker.identity);
+        }
+    }
+}
+
+fn elapsed_seconds(since: Timestamp, now: Timestamp) -> f32 {
+    let elapsed_micros = now.to_micros_since_unix_epoch() - since.to_micros_since_unix_epoch();
+
+    elapsed_micros.max(0) as f32 / 1_000_000.0
+}
@@ -1,13 +1,23 @@
-use crate::elements::{character::player, world_scene::world_scene};
+use crate::elements::character::{LinkdeadTimer, PlayerPresence, PlayerSession, player};
+use crate::elements::enemy::EnemyAiTimer;
+use crate::elements::server_config::ServerConfig;
+use crate::elements::world_clock::{WorldClock, WorldClockTimer};
+use crate::world_state::item_catalog::ItemCatalog;
+use crate::world_state::loot_catalog::LootCatalog;
 use crate::world_state::world_scene_config::WorldSceneConfig;
 
-use spacetimedb::{ReducerContext, Table, reducer};
+use spacetimedb::{ReducerContext, Timestamp, reducer};
 
 #[reducer(init)]
 pub fn init(ctx: &ReducerContext) -> Result<(), String> {
     log::trace!("Initializing...");
 
+    ServerConfig::initialize(ctx);
+    ItemCatalog::initialize(ctx)?;
+    LootCatalog::initialize(ctx)?;
     WorldSceneConfig::initialize_all_scenes(ctx)?;
+    WorldClockTimer::initialize(ctx);
+    EnemyAiTimer::initialize(ctx);
 
     Ok(())
 }
@@ -19,11 +29,41 @@ pub fn identity_connected(ctx: &ReducerContext) -> Result<(), String> {
         ctx.sender
     );
 
-    if ctx.db.player().iter().any(|p| p.identity == ctx.sender) {
-        return Err("Player already in the game".to_string());
-    }
+    PlayerSession::begin(ctx);
 
-    Ok(())
+    let Some(mut player) = ctx.db.player().identity().find(ctx.sender) else {
+        return Ok(());
+    };
+
+    match player.presence {
+        PlayerPresence::Online => {
+            // The previous connection dropped without its disconnect being
+            // handled yet, or the player opened a second client; either way
+            // the new connection takes over and the player stays in place.
+            log::info!(
+                "Player {} ({}) took over the session from a previous connection",
+                player.name,
+                player.identity
+            );
+
+            Ok(())
+        }
+        PlayerPresence::Linkdead => {
+            LinkdeadTimer::cancel(ctx, ctx.sender);
+
+            player.presence = PlayerPresence::Online;
+            let player = ctx.db.player().identity().update(player);
+
+            log::info!(
+                "Player {} ({}) reconnected within the grace period",
+                player.name,
+                player.identity
+            );
+
+            Ok(())
+        }
+        PlayerPresence::Offline => Ok(()),
+    }
 }
 
 #[reducer(client_disconnected)]
@@ -33,24 +73,77 @@ pub fn identity_disconnected(ctx: &ReducerContext) -> Result<(), String> {
         ctx.sender
     );
 
-    ctx.db.player().identity().delete(ctx.sender);
+    if !PlayerSession::end(ctx) {
+        log::info!(
+            "Ignoring disconnect of {}: the session was taken over",
+            ctx.sender
+        );
+        return Ok(());
+    }
+
+    // The row is kept so the identity can resume where it left off.
+    if let Some(mut player) = ctx.db.player().identity().find(ctx.sender)
+        && player.is_online()
+    {
+        player.presence = PlayerPresence::Linkdead;
+        ctx.db.player().identity().update(player);
+
+        let grace_period = ServerConfig::get(ctx).linkdead_grace_period;
+        LinkdeadTimer::schedule(ctx, ctx.sender, grace_period);
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn expire_linkdead(ctx: &ReducerContext, timer: LinkdeadTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer expire_linkdead may only be invoked by the scheduler".to_string());
+    }
+
+    let Some(mut player) = ctx.db.player().identity().find(timer.identity) else {
+        return Ok(());
+    };
+
+    if player.presence != PlayerPresence::Linkdead {
+        return Ok(());
+    }
+
+    player.presence = PlayerPresence::Offline;
+    let player = ctx.db.player().identity().update(player);
+
+    log::info!(
+        "Player {} ({}) went offline after the grace period",
+        player.name,
+        player.identity
+    );
+
+    Ok(())
+}
+
+#[reducer]
+pub fn update_timestamp(ctx: &ReducerContext, timer: WorldClockTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Reducer update_timestamp may only be invoked by the scheduler".to_string());
+    }
+
+    log::trace!("Advancing world clocks (timer {})", timer.scheduled_id);
+
+    WorldClock::advance(ctx);
 
     Ok(())
 }
 
+/// Round trip target for client clock offset estimation. Clients read the
+/// server time of the call from the reducer event, so nothing is stored.
 #[reducer]
-pub fn update_timestamp(ctx: &ReducerContext) -> Result<(), String> {
-    log::trace!("Updating timestamp...");
-
-    let mut world_scene = ctx
-        .db
-        .world_scene()
-        .iter()
-        .find(|scene| scene.scene_id == 1)
-        .ok_or("World scene not found")?;
-
-    world_scene.last_update_time = ctx.timestamp;
-    ctx.db.world_scene().scene_id().update(world_scene);
+pub fn clock_probe(ctx: &ReducerContext, client_time: Timestamp) -> Result<(), String> {
+    log::trace!(
+        "Clock probe from {} sent at {:?}, received at {:?}",
+        ctx.sender,
+        client_time,
+        ctx.timestamp
+    );
 
     Ok(())
 }
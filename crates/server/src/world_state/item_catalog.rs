@@ -0,0 +1,147 @@
+use crate::elements::item::{ItemDefinition, ItemEffect, ItemKind, ItemRarity, item_definition};
+
+use serde::Deserialize;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Every item in the game as authored in `crates/server/items/items.ron`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ItemCatalog {
+    pub items: Vec<ItemEntry>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ItemEntry {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub kind: ItemKind,
+    pub rarity: ItemRarity,
+    pub max_stack: u32,
+    #[serde(default)]
+    pub effects: Vec<ItemEffect>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemCatalogError {
+    Parse { message: String },
+    EmptyName,
+    DuplicateName { item: String },
+    InvalidMaxStack { item: String },
+    StackableArtifact { item: String },
+    EffectsOnTreasure { item: String },
+}
+
+impl fmt::Display for ItemCatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { message } => {
+                write!(f, "{}: failed to parse: {message}", ItemCatalog::FILE)
+            }
+            Self::EmptyName => write!(f, "item name cannot be empty"),
+            Self::DuplicateName { item } => write!(f, "item {item} is defined more than once"),
+            Self::InvalidMaxStack { item } => {
+                write!(f, "item {item}: max stack must be positive")
+            }
+            Self::StackableArtifact { item } => {
+                write!(f, "item {item}: artifacts cannot stack")
+            }
+            Self::EffectsOnTreasure { item } => {
+                write!(f, "item {item}: only artifacts can have effects")
+            }
+        }
+    }
+}
+
+impl ItemCatalog {
+    const FILE: &'static str = "items.ron";
+    const SOURCE: &'static str = include_str!("../../items/items.ron");
+
+    pub fn load() -> Result<Self, String> {
+        let catalog: ItemCatalog = ron::from_str(Self::SOURCE).map_err(|e| {
+            ItemCatalogError::Parse {
+                message: e.to_string(),
+            }
+            .to_string()
+        })?;
+
+        catalog.validate().map_err(|errors| {
+            errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join("; ")
+        })?;
+
+        Ok(catalog)
+    }
+
+    /// Returns all problems instead of stopping at the first one.
+    fn validate(&self) -> Result<(), Vec<ItemCatalogError>> {
+        let mut errors = Vec::new();
+        let mut names = HashSet::new();
+
+        for item in &self.items {
+            if item.name.trim().is_empty() {
+                errors.push(ItemCatalogError::EmptyName);
+            } else if !names.insert(item.name.as_str()) {
+                errors.push(ItemCatalogError::DuplicateName {
+                    item: item.name.clone(),
+                });
+            }
+
+            if item.max_stack == 0 {
+                errors.push(ItemCatalogError::InvalidMaxStack {
+                    item: item.name.clone(),
+                });
+            }
+
+            match item.kind {
+                ItemKind::Artifact if item.max_stack > 1 => {
+                    errors.push(ItemCatalogError::StackableArtifact {
+                        item: item.name.clone(),
+                    });
+                }
+                ItemKind::Treasure if !item.effects.is_empty() => {
+                    errors.push(ItemCatalogError::EffectsOnTreasure {
+                        item: item.name.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub fn initialize(ctx: &ReducerContext) -> Result<(), String> {
+        let catalog = Self::load()?;
+
+        for entry in catalog.items {
+            let item = ctx.db.item_definition().insert(ItemDefinition {
+                item_id: 0,
+                name: entry.name,
+                description: entry.description,
+                kind: entry.kind,
+                rarity: entry.rarity,
+                max_stack: entry.max_stack,
+                effects: entry.effects,
+            });
+
+            log::info!(
+                "Item {} ({:?} {:?}) registered with id: {}",
+                item.name,
+                item.rarity,
+                item.kind,
+                item.item_id
+            );
+        }
+
+        Ok(())
+    }
+}
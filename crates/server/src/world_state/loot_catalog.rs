@@ -0,0 +1,224 @@
+use crate::elements::item::{ItemDefinition, item_definition};
+use crate::elements::loot::{
+    LootDistribution, LootItem, LootTable, RarityTier, WeightedLoot, loot_table,
+};
+
+use serde::Deserialize;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Every loot table as authored in `crates/server/items/loot.ron`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct LootCatalog {
+    pub tables: Vec<LootTableEntry>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LootTableEntry {
+    pub name: String,
+    pub distribution: LootDistribution,
+    #[serde(default)]
+    pub guaranteed: Vec<LootItemEntry>,
+    #[serde(default)]
+    pub rolls: u32,
+    #[serde(default)]
+    pub nothing_weight: u32,
+    #[serde(default)]
+    pub tiers: Vec<RarityTier>,
+    #[serde(default)]
+    pub entries: Vec<LootItemEntry>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LootItemEntry {
+    pub item: String,
+    /// Only used by `entries`.
+    #[serde(default)]
+    pub weight: u32,
+    #[serde(default = "one")]
+    pub min_quantity: u32,
+    #[serde(default = "one")]
+    pub max_quantity: u32,
+}
+
+fn one() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LootCatalogError {
+    Parse { message: String },
+    DuplicateName { table: String },
+    UnknownItem { table: String, item: String },
+    InvalidQuantity { table: String, item: String },
+    ZeroWeight { table: String, item: String },
+    EmptyTier { table: String, tier: String },
+    NothingToRoll { table: String },
+}
+
+impl fmt::Display for LootCatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { message } => {
+                write!(f, "{}: failed to parse: {message}", LootCatalog::FILE)
+            }
+            Self::DuplicateName { table } => {
+                write!(f, "loot table {table} is defined more than once")
+            }
+            Self::UnknownItem { table, item } => {
+                write!(f, "loot table {table}: unknown item {item}")
+            }
+            Self::InvalidQuantity { table, item } => write!(
+                f,
+                "loot table {table}: {item} needs a positive quantity range"
+            ),
+            Self::ZeroWeight { table, item } => {
+                write!(f, "loot table {table}: {item} needs a positive weight")
+            }
+            Self::EmptyTier { table, tier } => {
+                write!(f, "loot table {table}: tier {tier} has no entries")
+            }
+            Self::NothingToRoll { table } => {
+                write!(f, "loot table {table} has rolls but no entries")
+            }
+        }
+    }
+}
+
+impl LootCatalog {
+    const FILE: &'static str = "loot.ron";
+    const SOURCE: &'static str = include_str!("../../items/loot.ron");
+
+    /// Loads the catalog into `loot_table`. Items are looked up by name, so
+    /// this runs after `ItemCatalog::initialize`.
+    pub fn initialize(ctx: &ReducerContext) -> Result<(), String> {
+        let catalog: LootCatalog = ron::from_str(Self::SOURCE).map_err(|e| {
+            LootCatalogError::Parse {
+                message: e.to_string(),
+            }
+            .to_string()
+        })?;
+
+        let tables = catalog.resolve(ctx).map_err(|errors| {
+            errors
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join("; ")
+        })?;
+
+        for table in tables {
+            let table = ctx.db.loot_table().insert(table);
+
+            log::info!(
+                "Loot table {} registered with {} entries",
+                table.name,
+                table.guaranteed.len() + table.entries.len()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Resolves item names and checks every table, returning all problems
+    /// instead of stopping at the first one.
+    fn resolve(&self, ctx: &ReducerContext) -> Result<Vec<LootTable>, Vec<LootCatalogError>> {
+        let mut errors = Vec::new();
+        let mut names = HashSet::new();
+        let mut tables = Vec::new();
+
+        for entry in &self.tables {
+            if !names.insert(entry.name.as_str()) {
+                errors.push(LootCatalogError::DuplicateName {
+                    table: entry.name.clone(),
+                });
+            }
+
+            let mut resolve_item = |item: &LootItemEntry| -> Option<LootItem> {
+                let Some(definition) = ctx.db.item_definition().name().find(&item.item) else {
+                    errors.push(LootCatalogError::UnknownItem {
+                        table: entry.name.clone(),
+                        item: item.item.clone(),
+                    });
+                    return None;
+                };
+
+                if item.min_quantity == 0 || item.min_quantity > item.max_quantity {
+                    errors.push(LootCatalogError::InvalidQuantity {
+                        table: entry.name.clone(),
+                        item: item.item.clone(),
+                    });
+                    return None;
+                }
+
+                Some(Self::loot_item(&definition, item))
+            };
+
+            let guaranteed: Vec<LootItem> = entry
+                .guaranteed
+                .iter()
+                .filter_map(&mut resolve_item)
+                .collect();
+            let weighted: Vec<(LootItem, u32, &str)> = entry
+                .entries
+                .iter()
+                .filter_map(|item| {
+                    resolve_item(item).map(|loot| (loot, item.weight, item.item.as_str()))
+                })
+                .collect();
+
+            let mut entries = Vec::new();
+            for (item, weight, name) in weighted {
+                if weight == 0 {
+                    errors.push(LootCatalogError::ZeroWeight {
+                        table: entry.name.clone(),
+                        item: name.to_string(),
+                    });
+                }
+
+                entries.push(WeightedLoot { item, weight });
+            }
+
+            if entry.rolls > 0 && entries.is_empty() {
+                errors.push(LootCatalogError::NothingToRoll {
+                    table: entry.name.clone(),
+                });
+            }
+
+            for tier in &entry.tiers {
+                if !entries.iter().any(|loot| loot.item.rarity == tier.rarity) {
+                    errors.push(LootCatalogError::EmptyTier {
+                        table: entry.name.clone(),
+                        tier: format!("{:?}", tier.rarity),
+                    });
+                }
+            }
+
+            tables.push(LootTable {
+                name: entry.name.clone(),
+                distribution: entry.distribution,
+                guaranteed,
+                rolls: entry.rolls,
+                nothing_weight: entry.nothing_weight,
+                tiers: entry.tiers.clone(),
+                entries,
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(tables)
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn loot_item(definition: &ItemDefinition, item: &LootItemEntry) -> LootItem {
+        LootItem {
+            item_id: definition.item_id,
+            rarity: definition.rarity,
+            min_quantity: item.min_quantity,
+            max_quantity: item.max_quantity,
+        }
+    }
+}
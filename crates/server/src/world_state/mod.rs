@@ -1,2 +1,6 @@
+pub mod item_catalog;
+pub mod loot_catalog;
 pub mod registration;
+pub mod scene_definition;
+pub mod scene_transition;
 pub mod world_scene_config;
@@ -1,6 +1,11 @@
-use crate::elements::character::{DBPlayerState, DbPlayer, player};
-use crate::elements::world_scene::{WorldScene, world_scene};
+use crate::elements::character::{DBPlayerState, DbPlayer, LinkdeadTimer, PlayerPresence, player};
+use crate::elements::checkpoint::Checkpoint;
+use crate::elements::movement::MovementTracker;
+use crate::elements::server_config::ServerConfig;
+use crate::elements::world_clock::WorldClock;
+use crate::elements::world_scene::world_scene;
 
+use game_rules::names::validate_player_name;
 use spacetimedb::{ReducerContext, Table, reducer};
 
 #[reducer]
@@ -12,8 +17,12 @@ pub fn register_player(ctx: &ReducerContext, name: String, scene_id: u32) -> Res
         scene_id
     );
 
-    if ctx.db.player().identity().find(ctx.sender).is_some() {
-        return Err("Player already registered".to_string());
+    let name = validate_player_name(&name)
+        .map_err(|e| e.to_string())?
+        .to_string();
+
+    if let Some(player) = ctx.db.player().identity().find(ctx.sender) {
+        return resume_player(ctx, player, name);
     }
 
     let scene = ctx
@@ -23,26 +32,27 @@ pub fn register_player(ctx: &ReducerContext, name: String, scene_id: u32) -> Res
         .find(scene_id)
         .ok_or("Scene does not exist")?;
 
-    if name.trim().is_empty() {
-        return Err("Name cannot be empty".to_string());
-    }
-
-    if name.len() > 20 {
-        return Err("Name too long (max 20 characters)".to_string());
-    }
-
-    let is_host = ctx.db.player().count() == 0;
+    let is_host = DbPlayer::is_scene_empty(ctx, scene_id);
     if is_host {
-        WorldScene::set_creation_time(ctx, ctx.timestamp)?;
+        WorldClock::restart(ctx, scene_id)?;
     }
 
     match ctx.db.player().try_insert(DbPlayer {
         player_id: 0,
         identity: ctx.sender,
-        name: name.trim().to_string(),
+        name,
+        scene_id,
         state: DBPlayerState::with_position(scene.spawn_point),
+        state_updated_at: ctx.timestamp,
+        presence: PlayerPresence::Online,
+        health: ServerConfig::get(ctx).player_max_health,
+        deaths: 0,
+        invulnerable_until: None,
+        checkpoint_id: None,
     }) {
         Ok(player) => {
+            MovementTracker::restart(ctx, player.identity);
+
             log::info!(
                 "Player {} registered successfully with name: {} and id: {} in scene: {} (host: {})",
                 player.identity,
@@ -61,3 +71,43 @@ pub fn register_player(ctx: &ReducerContext, name: String, scene_id: u32) -> Res
 
     Ok(())
 }
+
+/// Brings a returning identity back online at its last scene and position.
+/// A player that had gone offline comes back at its last checkpoint instead,
+/// if it activated one. A player that reconnected within its linkdead grace
+/// period is already online again, so registering once more is harmless.
+fn resume_player(ctx: &ReducerContext, mut player: DbPlayer, name: String) -> Result<(), String> {
+    if !player.is_present() {
+        if DbPlayer::is_scene_empty(ctx, player.scene_id) {
+            WorldClock::restart(ctx, player.scene_id)?;
+        }
+
+        if let Some(checkpoint) = Checkpoint::last_activated(ctx, &player) {
+            player.state.teleport(checkpoint.position);
+        }
+    }
+
+    // A new client session numbers its inputs from the start again.
+    player.state.input_sequence = 0;
+    player.state_updated_at = ctx.timestamp;
+
+    LinkdeadTimer::cancel(ctx, player.identity);
+
+    player.name = name;
+    player.presence = PlayerPresence::Online;
+    let player = ctx.db.player().identity().update(player);
+
+    // The client boots wherever the row says, which may not be where it last reported.
+    MovementTracker::restart(ctx, player.identity);
+
+    log::info!(
+        "Player {} resumed as {} in scene {} at ({}, {})",
+        player.identity,
+        player.name,
+        player.scene_id,
+        player.state.position.x,
+        player.state.position.y
+    );
+
+    Ok(())
+}
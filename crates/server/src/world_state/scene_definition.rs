@@ -0,0 +1,613 @@
+use crate::elements::DbVector2;
+use crate::elements::enemy::EnemyKind;
+use crate::elements::kinematics::MovementPath;
+
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Files making up one biome: the hand-written definition and the layout
+/// exported from the Godot scene by `scene-export`.
+pub struct SceneSource {
+    pub file: &'static str,
+    pub definition: &'static str,
+    pub layout_file: &'static str,
+    pub layout: &'static str,
+}
+
+/// One biome as authored in `crates/server/scenes/*.ron`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct SceneDefinition {
+    pub name: String,
+    pub bounds: SceneBounds,
+    pub coin_respawn_seconds: u64,
+    pub coin_pickup_radius: f32,
+    /// Loot table from `items/loot.ron` rolled for every coin picked up.
+    #[serde(default)]
+    pub coin_loot_table: Option<String>,
+    /// Loot table from `items/loot.ron` rolled on a player's first completion.
+    #[serde(default)]
+    pub completion_loot_table: Option<String>,
+    #[serde(skip)]
+    pub layout: SceneLayout,
+}
+
+/// Placements generated from the Godot scene, see `crates/scene-export`.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct SceneLayout {
+    pub spawn_point: DbVector2,
+    #[serde(default)]
+    pub coins: Vec<CoinPlacement>,
+    #[serde(default)]
+    pub platforms: Vec<PlatformPlacement>,
+    #[serde(default)]
+    pub enemies: Vec<EnemyPlacement>,
+    #[serde(default)]
+    pub portals: Vec<PortalPlacement>,
+    #[serde(default)]
+    pub checkpoints: Vec<CheckpointPlacement>,
+    #[serde(default)]
+    pub chests: Vec<ChestPlacement>,
+    /// Reaching this completes the scene.
+    #[serde(default)]
+    pub goal: Option<DbVector2>,
+    /// Height of the kill zone's boundary; players below it have fallen out.
+    #[serde(default)]
+    pub kill_zone: Option<f32>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SceneBounds {
+    pub min: DbVector2,
+    pub max: DbVector2,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CoinPlacement {
+    pub position: DbVector2,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PlatformPlacement {
+    pub name: String,
+    pub position: DbVector2,
+    #[serde(default)]
+    pub path: Option<MovementPath>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct EnemyPlacement {
+    pub name: String,
+    pub kind: EnemyKind,
+    pub position: DbVector2,
+    /// Patrol route; an enemy without one stands guard at `position`.
+    #[serde(default)]
+    pub path: Option<MovementPath>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CheckpointPlacement {
+    pub name: String,
+    pub position: DbVector2,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChestPlacement {
+    pub name: String,
+    pub position: DbVector2,
+    pub loot_table: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PortalPlacement {
+    /// Name of the portal node in the Godot scene, which the client enters it by.
+    pub name: String,
+    pub trigger_position: DbVector2,
+    pub trigger_size: DbVector2,
+    pub target_scene: String,
+    pub target_spawn: DbVector2,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SceneDefinitionError {
+    Parse {
+        file: String,
+        message: String,
+    },
+    EmptyName {
+        file: String,
+    },
+    DuplicateSceneName {
+        scene: String,
+    },
+    InvalidBounds {
+        scene: String,
+    },
+    InvalidCoinRespawnDelay {
+        scene: String,
+    },
+    InvalidCoinPickupRadius {
+        scene: String,
+    },
+    SpawnOutOfBounds {
+        scene: String,
+        spawn: DbVector2,
+    },
+    DuplicateCoinPosition {
+        scene: String,
+        position: DbVector2,
+    },
+    CoinOutOfBounds {
+        scene: String,
+        position: DbVector2,
+    },
+    InvalidPlatformPath {
+        scene: String,
+        platform: String,
+    },
+    InvalidEnemyPatrol {
+        scene: String,
+        enemy: String,
+    },
+    CheckpointOutOfBounds {
+        scene: String,
+        checkpoint: String,
+    },
+    ChestOutOfBounds {
+        scene: String,
+        chest: String,
+    },
+    GoalOutOfBounds {
+        scene: String,
+        goal: DbVector2,
+    },
+    MissingGoal {
+        scene: String,
+    },
+    DuplicatePortalName {
+        scene: String,
+        portal: String,
+    },
+    InvalidPortalSize {
+        scene: String,
+        portal: String,
+    },
+    UnknownPortalTarget {
+        scene: String,
+        target: String,
+    },
+    PortalSpawnOutOfBounds {
+        scene: String,
+        target: String,
+        spawn: DbVector2,
+    },
+}
+
+impl fmt::Display for SceneDefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse { file, message } => write!(f, "{file}: failed to parse: {message}"),
+            Self::EmptyName { file } => write!(f, "{file}: scene name cannot be empty"),
+            Self::DuplicateSceneName { scene } => {
+                write!(f, "scene {scene} is defined more than once")
+            }
+            Self::InvalidBounds { scene } => {
+                write!(f, "scene {scene}: bounds min must be below bounds max")
+            }
+            Self::InvalidCoinRespawnDelay { scene } => {
+                write!(f, "scene {scene}: coin respawn delay must be positive")
+            }
+            Self::InvalidCoinPickupRadius { scene } => {
+                write!(f, "scene {scene}: coin pickup radius must be positive")
+            }
+            Self::SpawnOutOfBounds { scene, spawn } => {
+                write!(f, "scene {scene}: spawn point {spawn} is out of bounds")
+            }
+            Self::DuplicateCoinPosition { scene, position } => {
+                write!(f, "scene {scene}: more than one coin at {position}")
+            }
+            Self::CoinOutOfBounds { scene, position } => {
+                write!(f, "scene {scene}: coin at {position} is out of bounds")
+            }
+            Self::InvalidPlatformPath { scene, platform } => {
+                write!(
+                    f,
+                    "scene {scene}: platform {platform} needs two waypoints, a positive period and a non-zero easing"
+                )
+            }
+            Self::InvalidEnemyPatrol { scene, enemy } => {
+                write!(
+                    f,
+                    "scene {scene}: enemy {enemy} needs at least two patrol points"
+                )
+            }
+            Self::CheckpointOutOfBounds { scene, checkpoint } => {
+                write!(f, "scene {scene}: checkpoint {checkpoint} is out of bounds")
+            }
+            Self::ChestOutOfBounds { scene, chest } => {
+                write!(f, "scene {scene}: chest {chest} is out of bounds")
+            }
+            Self::GoalOutOfBounds { scene, goal } => {
+                write!(f, "scene {scene}: goal {goal} is out of bounds")
+            }
+            Self::MissingGoal { scene } => {
+                write!(
+                    f,
+                    "scene {scene}: a completion loot table needs a goal in the layout"
+                )
+            }
+            Self::DuplicatePortalName { scene, portal } => {
+                write!(f, "scene {scene}: more than one portal named {portal}")
+            }
+            Self::InvalidPortalSize { scene, portal } => {
+                write!(
+                    f,
+                    "scene {scene}: portal {portal} has an empty trigger area"
+                )
+            }
+            Self::UnknownPortalTarget { scene, target } => {
+                write!(f, "scene {scene}: portal targets unknown scene {target}")
+            }
+            Self::PortalSpawnOutOfBounds {
+                scene,
+                target,
+                spawn,
+            } => write!(
+                f,
+                "scene {scene}: portal spawn {spawn} is out of bounds of scene {target}"
+            ),
+        }
+    }
+}
+
+impl SceneBounds {
+    pub fn contains(&self, position: &DbVector2) -> bool {
+        position.x >= self.min.x
+            && position.x <= self.max.x
+            && position.y >= self.min.y
+            && position.y <= self.max.y
+    }
+
+    fn is_valid(&self) -> bool {
+        self.min.x < self.max.x && self.min.y < self.max.y
+    }
+}
+
+impl SceneDefinition {
+    pub fn parse(source: &SceneSource) -> Result<Self, SceneDefinitionError> {
+        let mut definition: SceneDefinition = Self::parse_file(source.file, source.definition)?;
+        definition.layout = Self::parse_file(source.layout_file, source.layout)?;
+
+        Ok(definition)
+    }
+
+    fn parse_file<T: for<'de> Deserialize<'de>>(
+        file: &str,
+        source: &str,
+    ) -> Result<T, SceneDefinitionError> {
+        ron::from_str(source).map_err(|e| SceneDefinitionError::Parse {
+            file: file.to_string(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Checks every definition on its own and against the others,
+    /// returning all problems instead of stopping at the first one.
+    pub fn validate_all(
+        definitions: &[(&str, SceneDefinition)],
+    ) -> Result<(), Vec<SceneDefinitionError>> {
+        let mut errors = Vec::new();
+        let mut names = HashSet::new();
+
+        for (file, definition) in definitions {
+            if definition.name.trim().is_empty() {
+                errors.push(SceneDefinitionError::EmptyName {
+                    file: file.to_string(),
+                });
+            } else if !names.insert(definition.name.as_str()) {
+                errors.push(SceneDefinitionError::DuplicateSceneName {
+                    scene: definition.name.clone(),
+                });
+            }
+
+            definition.validate(&mut errors);
+        }
+
+        for (_, definition) in definitions {
+            definition.validate_portals(definitions, &mut errors);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn validate(&self, errors: &mut Vec<SceneDefinitionError>) {
+        let scene = &self.name;
+
+        if !self.bounds.is_valid() {
+            errors.push(SceneDefinitionError::InvalidBounds {
+                scene: scene.clone(),
+            });
+        }
+
+        if self.coin_respawn_seconds == 0 {
+            errors.push(SceneDefinitionError::InvalidCoinRespawnDelay {
+                scene: scene.clone(),
+            });
+        }
+
+        if self.coin_pickup_radius <= 0.0 {
+            errors.push(SceneDefinitionError::InvalidCoinPickupRadius {
+                scene: scene.clone(),
+            });
+        }
+
+        if !self.bounds.contains(&self.layout.spawn_point) {
+            errors.push(SceneDefinitionError::SpawnOutOfBounds {
+                scene: scene.clone(),
+                spawn: self.layout.spawn_point.clone(),
+            });
+        }
+
+        let mut coin_positions = HashSet::new();
+        for coin in &self.layout.coins {
+            if !coin_positions.insert(&coin.position) {
+                errors.push(SceneDefinitionError::DuplicateCoinPosition {
+                    scene: scene.clone(),
+                    position: coin.position.clone(),
+                });
+            }
+
+            if !self.bounds.contains(&coin.position) {
+                errors.push(SceneDefinitionError::CoinOutOfBounds {
+                    scene: scene.clone(),
+                    position: coin.position.clone(),
+                });
+            }
+        }
+
+        for platform in &self.layout.platforms {
+            if platform.path.as_ref().is_some_and(|path| !path.is_valid()) {
+                errors.push(SceneDefinitionError::InvalidPlatformPath {
+                    scene: scene.clone(),
+                    platform: platform.name.clone(),
+                });
+            }
+        }
+
+        for enemy in &self.layout.enemies {
+            if enemy
+                .path
+                .as_ref()
+                .is_some_and(|path| path.waypoints.len() < 2)
+            {
+                errors.push(SceneDefinitionError::InvalidEnemyPatrol {
+                    scene: scene.clone(),
+                    enemy: enemy.name.clone(),
+                });
+            }
+        }
+
+        for checkpoint in &self.layout.checkpoints {
+            if !self.bounds.contains(&checkpoint.position) {
+                errors.push(SceneDefinitionError::CheckpointOutOfBounds {
+                    scene: scene.clone(),
+                    checkpoint: checkpoint.name.clone(),
+                });
+            }
+        }
+
+        for chest in &self.layout.chests {
+            if !self.bounds.contains(&chest.position) {
+                errors.push(SceneDefinitionError::ChestOutOfBounds {
+                    scene: scene.clone(),
+                    chest: chest.name.clone(),
+                });
+            }
+        }
+
+        match &self.layout.goal {
+            Some(goal) if !self.bounds.contains(goal) => {
+                errors.push(SceneDefinitionError::GoalOutOfBounds {
+                    scene: scene.clone(),
+                    goal: goal.clone(),
+                });
+            }
+            None if self.completion_loot_table.is_some() => {
+                errors.push(SceneDefinitionError::MissingGoal {
+                    scene: scene.clone(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_portals(
+        &self,
+        definitions: &[(&str, SceneDefinition)],
+        errors: &mut Vec<SceneDefinitionError>,
+    ) {
+        let mut portal_names = HashSet::new();
+        for portal in &self.layout.portals {
+            if !portal_names.insert(portal.name.as_str()) {
+                errors.push(SceneDefinitionError::DuplicatePortalName {
+                    scene: self.name.clone(),
+                    portal: portal.name.clone(),
+                });
+            }
+
+            if portal.trigger_size.x <= 0.0 || portal.trigger_size.y <= 0.0 {
+                errors.push(SceneDefinitionError::InvalidPortalSize {
+                    scene: self.name.clone(),
+                    portal: portal.name.clone(),
+                });
+            }
+
+            let Some((_, target)) = definitions
+                .iter()
+                .find(|(_, definition)| definition.name == portal.target_scene)
+            else {
+                errors.push(SceneDefinitionError::UnknownPortalTarget {
+                    scene: self.name.clone(),
+                    target: portal.target_scene.clone(),
+                });
+                continue;
+            };
+
+            if !target.bounds.contains(&portal.target_spawn) {
+                errors.push(SceneDefinitionError::PortalSpawnOutOfBounds {
+                    scene: self.name.clone(),
+                    target: portal.target_scene.clone(),
+                    spawn: portal.target_spawn.clone(),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world_state::world_scene_config::WorldSceneConfig;
+
+    fn scene(name: &str) -> SceneDefinition {
+        let source = format!(
+            r#"SceneDefinition(
+                name: "{name}",
+                bounds: (min: (x: -100.0, y: -100.0), max: (x: 100.0, y: 100.0)),
+                coin_respawn_seconds: 30,
+                coin_pickup_radius: 24.0,
+            )"#
+        );
+
+        let mut definition: SceneDefinition =
+            SceneDefinition::parse_file("test.ron", &source).unwrap();
+        definition.layout = SceneDefinition::parse_file(
+            "test.layout.ron",
+            "SceneLayout(spawn_point: (x: 0.0, y: 0.0))",
+        )
+        .unwrap();
+        definition
+    }
+
+    fn coin(x: f32, y: f32) -> CoinPlacement {
+        CoinPlacement {
+            position: DbVector2::new(x, y),
+        }
+    }
+
+    fn portal(name: &str, target_scene: &str) -> PortalPlacement {
+        PortalPlacement {
+            name: name.to_string(),
+            trigger_position: DbVector2::new(50.0, 0.0),
+            trigger_size: DbVector2::new(24.0, 40.0),
+            target_scene: target_scene.to_string(),
+            target_spawn: DbVector2::new(0.0, 0.0),
+        }
+    }
+
+    fn errors(definitions: Vec<SceneDefinition>) -> Vec<SceneDefinitionError> {
+        let definitions = definitions
+            .into_iter()
+            .map(|definition| ("test.ron", definition))
+            .collect::<Vec<_>>();
+
+        SceneDefinition::validate_all(&definitions)
+            .err()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn embedded_scenes_are_valid() {
+        let definitions = WorldSceneConfig::load_definitions().unwrap();
+
+        assert!(definitions.iter().any(|(_, scene)| scene.name == "Main"));
+    }
+
+    #[test]
+    fn valid_scenes_pass() {
+        let mut main = scene("Main");
+        main.layout.coins = vec![coin(10.0, 0.0), coin(20.0, 0.0)];
+        main.layout.portals = vec![portal("CavePortal", "Cave")];
+
+        assert_eq!(errors(vec![main, scene("Cave")]), Vec::new());
+    }
+
+    #[test]
+    fn duplicate_coin_positions_are_reported() {
+        let mut main = scene("Main");
+        main.layout.coins = vec![coin(10.0, 0.0), coin(10.0, 0.0)];
+
+        assert_eq!(
+            errors(vec![main]),
+            vec![SceneDefinitionError::DuplicateCoinPosition {
+                scene: "Main".to_string(),
+                position: DbVector2::new(10.0, 0.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn empty_names_are_reported() {
+        assert_eq!(
+            errors(vec![scene(" ")]),
+            vec![SceneDefinitionError::EmptyName {
+                file: "test.ron".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn duplicate_scene_names_are_reported() {
+        assert_eq!(
+            errors(vec![scene("Main"), scene("Main")]),
+            vec![SceneDefinitionError::DuplicateSceneName {
+                scene: "Main".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_spawns_are_reported() {
+        let mut main = scene("Main");
+        main.layout.spawn_point = DbVector2::new(0.0, 150.0);
+
+        assert_eq!(
+            errors(vec![main]),
+            vec![SceneDefinitionError::SpawnOutOfBounds {
+                scene: "Main".to_string(),
+                spawn: DbVector2::new(0.0, 150.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn portal_problems_are_reported() {
+        let mut main = scene("Main");
+        let mut outside = portal("CavePortal", "Cave");
+        outside.target_spawn = DbVector2::new(500.0, 0.0);
+        main.layout.portals = vec![outside, portal("CavePortal", "Nowhere")];
+
+        assert_eq!(
+            errors(vec![main, scene("Cave")]),
+            vec![
+                SceneDefinitionError::PortalSpawnOutOfBounds {
+                    scene: "Main".to_string(),
+                    target: "Cave".to_string(),
+                    spawn: DbVector2::new(500.0, 0.0),
+                },
+                SceneDefinitionError::DuplicatePortalName {
+                    scene: "Main".to_string(),
+                    portal: "CavePortal".to_string(),
+                },
+                SceneDefinitionError::UnknownPortalTarget {
+                    scene: "Main".to_string(),
+                    target: "Nowhere".to_string(),
+                },
+            ]
+        );
+    }
+}
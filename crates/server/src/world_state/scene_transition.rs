@@ -0,0 +1,67 @@
+use crate::elements::character::{DbPlayer, player};
+use crate::elements::movement::MovementTracker;
+use crate::elements::portal::Portal;
+use crate::elements::server_config::ServerConfig;
+use crate::elements::world_clock::WorldClock;
+use crate::elements::world_scene::world_scene;
+
+use spacetimedb::{ReducerContext, reducer};
+
+#[reducer]
+pub fn change_scene(ctx: &ReducerContext, portal_name: String) -> Result<(), String> {
+    log::trace!(
+        "Player {} is changing scene through portal {}",
+        ctx.sender,
+        portal_name
+    );
+
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not registered")?;
+
+    // Portals are named after their node, so they are only looked up in the player's scene.
+    let portal = Portal::find(ctx, player.scene_id, &portal_name)
+        .ok_or(format!("Portal {portal_name} is not in the player's scene"))?;
+
+    if !portal.contains(&player.state.position) {
+        return Err("Player is not inside the portal".to_string());
+    }
+
+    let target_scene = ctx
+        .db
+        .world_scene()
+        .scene_id()
+        .find(portal.target_scene_id)
+        .ok_or("Target scene does not exist")?;
+
+    if DbPlayer::is_scene_empty(ctx, target_scene.scene_id) {
+        WorldClock::restart(ctx, target_scene.scene_id)?;
+    }
+
+    let source_scene_id = player.scene_id;
+
+    player.scene_id = target_scene.scene_id;
+    player.state.teleport(portal.target_spawn);
+    player.state_updated_at = ctx.timestamp;
+    player.checkpoint_id = None;
+    let player = ctx.db.player().identity().update(player);
+
+    MovementTracker::settle(
+        ctx,
+        player.identity,
+        ServerConfig::get(ctx).movement_settle_period,
+    );
+
+    log::info!(
+        "Player {} ({}) moved from scene {} to scene: {}",
+        player.name,
+        player.identity,
+        source_scene_id,
+        target_scene.name
+    );
+
+    Ok(())
+}
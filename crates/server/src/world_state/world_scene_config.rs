@@ -1,146 +1,168 @@
-use crate::elements::DbVector2;
+use crate::elements::checkpoint::{Checkpoint, checkpoint};
+use crate::elements::chest::{Chest, chest};
 use crate::elements::coin::{Coin, coin};
-use crate::elements::world_scene::{WorldScene, world_scene};
+use crate::elements::enemy::{Enemy, enemy};
+use crate::elements::loot::LootTable;
+use crate::elements::platform::{Platform, platform};
+use crate::elements::portal::{Portal, portal};
+use crate::elements::world_clock::WorldClock;
+use crate::elements::world_scene::{KillZone, WorldScene, kill_zone, world_scene};
+use crate::world_state::scene_definition::{SceneDefinition, SceneSource};
 
-use itertools::Itertools;
-use spacetimedb::{ReducerContext, Table};
+use spacetimedb::{ReducerContext, Table, TimeDuration};
+use std::time::Duration;
 
-pub struct WorldSceneConfig {
-    name: &'static str,
-    spawn_point: DbVector2,
-    coins: &'static [CoinSetup],
-}
-
-pub struct CoinSetup {
-    position: DbVector2,
-}
+pub struct WorldSceneConfig;
 
 impl WorldSceneConfig {
-    const SCENES: &'static [WorldSceneConfig] = &[WorldSceneConfig {
-        name: "Main",
-        spawn_point: DbVector2 { x: -15.0, y: -35.0 },
-        coins: &[
-            CoinSetup {
-                position: DbVector2 { x: 80.0, y: -25.0 },
-            },
-            CoinSetup {
-                position: DbVector2 { x: 98.0, y: -25.0 },
-            },
-            CoinSetup {
-                position: DbVector2 { x: 178.0, y: -25.0 },
-            },
-            CoinSetup {
-                position: DbVector2 {
-                    x: 178.0,
-                    y: -120.0,
-                },
-            },
-            CoinSetup {
-                position: DbVector2 {
-                    x: 498.0,
-                    y: -104.0,
-                },
-            },
-            CoinSetup {
-                position: DbVector2 { x: 530.0, y: -88.0 },
-            },
-            CoinSetup {
-                position: DbVector2 {
-                    x: 690.0,
-                    y: -104.0,
-                },
-            },
-            CoinSetup {
-                position: DbVector2 {
-                    x: 626.0,
-                    y: -344.0,
-                },
-            },
-            CoinSetup {
-                position: DbVector2 {
-                    x: 642.0,
-                    y: -328.0,
-                },
-            },
-            CoinSetup {
-                position: DbVector2 {
-                    x: 674.0,
-                    y: -312.0,
-                },
-            },
-            CoinSetup {
-                position: DbVector2 {
-                    x: 834.0,
-                    y: -312.0,
-                },
-            },
-            CoinSetup {
-                position: DbVector2 {
-                    x: 882.0,
-                    y: -312.0,
-                },
-            },
-            CoinSetup {
-                position: DbVector2 {
-                    x: 754.0,
-                    y: -296.0,
-                },
-            },
-            CoinSetup {
-                position: DbVector2 {
-                    x: 784.0,
-                    y: -296.0,
-                },
-            },
-            CoinSetup {
-                position: DbVector2 { x: 834.0, y: 23.0 },
-            },
-        ],
-    }];
+    /// Scene files are embedded at build time, so a broken definition fails
+    /// module initialization instead of producing a half-built world.
+    const SCENE_SOURCES: &'static [SceneSource] = &[
+        SceneSource {
+            file: "main.ron",
+            definition: include_str!("../../scenes/main.ron"),
+            layout_file: "main.layout.ron",
+            layout: include_str!("../../scenes/main.layout.ron"),
+        },
+        SceneSource {
+            file: "cave.ron",
+            definition: include_str!("../../scenes/cave.ron"),
+            layout_file: "cave.layout.ron",
+            layout: include_str!("../../scenes/cave.layout.ron"),
+        },
+    ];
 
-    pub fn initialize_all_scenes(ctx: &ReducerContext) -> Result<(), String> {
-        for scene_config in Self::SCENES {
-            ctx.db.world_scene().insert(WorldScene::new(
-                scene_config.name.to_string(),
-                scene_config.spawn_point.clone(),
-                ctx.timestamp,
-            ));
+    pub fn load_definitions() -> Result<Vec<(&'static str, SceneDefinition)>, String> {
+        let mut definitions = Vec::new();
+        let mut errors = Vec::new();
+
+        for source in Self::SCENE_SOURCES {
+            match SceneDefinition::parse(source) {
+                Ok(definition) => definitions.push((source.file, definition)),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        if let Err(validation_errors) = SceneDefinition::validate_all(&definitions) {
+            errors.extend(validation_errors);
+        }
 
-            let world_scene = ctx
-                .db
-                .world_scene()
+        if errors.is_empty() {
+            Ok(definitions)
+        } else {
+            Err(errors
                 .iter()
-                .find(|scene| scene.name == scene_config.name)
-                .ok_or("Scene does not exist")?;
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join("; "))
+        }
+    }
+
+    pub fn initialize_all_scenes(ctx: &ReducerContext) -> Result<(), String> {
+        let definitions = Self::load_definitions()?;
+
+        for (_, definition) in &definitions {
+            for loot_table in [
+                &definition.coin_loot_table,
+                &definition.completion_loot_table,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                LootTable::find(ctx, loot_table)?;
+            }
+
+            ctx.db.world_scene().insert(WorldScene {
+                goal: definition.layout.goal.clone(),
+                coin_loot_table: definition.coin_loot_table.clone(),
+                completion_loot_table: definition.completion_loot_table.clone(),
+                ..WorldScene::new(
+                    definition.name.clone(),
+                    definition.layout.spawn_point.clone(),
+                    TimeDuration::from(Duration::from_secs(definition.coin_respawn_seconds)),
+                    definition.coin_pickup_radius,
+                )
+            });
+
+            let world_scene = Self::find_scene(ctx, &definition.name)?;
+            WorldClock::initialize(ctx, world_scene.scene_id);
+
+            if let Some(y) = definition.layout.kill_zone {
+                ctx.db.kill_zone().insert(KillZone {
+                    scene_id: world_scene.scene_id,
+                    y,
+                });
+            }
 
-            Self::initialize_coins(ctx, scene_config, &world_scene)?;
+            Self::initialize_coins(ctx, definition, &world_scene)?;
+            Self::initialize_platforms(ctx, definition, &world_scene);
+            Self::initialize_enemies(ctx, definition, &world_scene)?;
+            Self::initialize_checkpoints(ctx, definition, &world_scene);
+            Self::initialize_chests(ctx, definition, &world_scene)?;
 
-            log::info!("Initialized scene: {}", scene_config.name);
+            log::info!("Initialized scene: {}", definition.name);
+        }
+
+        // Portals may point at any scene, so they are linked once every scene exists.
+        for (_, definition) in &definitions {
+            Self::initialize_portals(ctx, definition)?;
         }
 
         Ok(())
     }
 
-    fn initialize_coins(
-        ctx: &ReducerContext,
-        scene_config: &WorldSceneConfig,
-        world_scene: &WorldScene,
-    ) -> Result<(), String> {
-        let coin_unique_positions = scene_config
-            .coins
+    fn find_scene(ctx: &ReducerContext, name: &str) -> Result<WorldScene, String> {
+        ctx.db
+            .world_scene()
             .iter()
-            .map(|setup| setup.position.clone())
-            .unique()
-            .collect::<Vec<_>>();
+            .find(|scene| scene.name == name)
+            .ok_or(format!("Scene {} does not exist", name))
+    }
 
-        if coin_unique_positions.is_empty() {
+    fn initialize_portals(
+        ctx: &ReducerContext,
+        definition: &SceneDefinition,
+    ) -> Result<(), String> {
+        if definition.layout.portals.is_empty() {
             return Ok(());
         }
 
-        for position in coin_unique_positions {
+        let source_scene = Self::find_scene(ctx, &definition.name)?;
+
+        for setup in &definition.layout.portals {
+            let target_scene = Self::find_scene(ctx, &setup.target_scene)?;
+
+            let inserted_portal = ctx.db.portal().insert(Portal {
+                portal_id: 0,
+                source_scene_id: source_scene.scene_id,
+                name: setup.name.clone(),
+                trigger_position: setup.trigger_position.clone(),
+                trigger_size: setup.trigger_size.clone(),
+                target_scene_id: target_scene.scene_id,
+                target_spawn: setup.target_spawn.clone(),
+            });
+
+            log::info!(
+                "Portal {} ({}) registered from scene {} to scene {}",
+                inserted_portal.name,
+                inserted_portal.portal_id,
+                source_scene.name,
+                target_scene.name
+            );
+        }
+
+        Ok(())
+    }
+
+    fn initialize_coins(
+        ctx: &ReducerContext,
+        definition: &SceneDefinition,
+        world_scene: &WorldScene,
+    ) -> Result<(), String> {
+        for setup in &definition.layout.coins {
             let coin = Coin {
                 coin_id: 0,
-                position: position.clone(),
+                position: setup.position.clone(),
                 scene_id: world_scene.scene_id,
                 collected_by: None,
             };
@@ -162,4 +184,105 @@ impl WorldSceneConfig {
 
         Ok(())
     }
+
+    fn initialize_platforms(
+        ctx: &ReducerContext,
+        definition: &SceneDefinition,
+        world_scene: &WorldScene,
+    ) {
+        for setup in &definition.layout.platforms {
+            let inserted_platform = ctx.db.platform().insert(Platform {
+                platform_id: 0,
+                scene_id: world_scene.scene_id,
+                name: setup.name.clone(),
+                rest_position: setup.position.clone(),
+                path: setup.path.clone(),
+            });
+
+            log::info!(
+                "Platform {} registered with id: {}",
+                inserted_platform.name,
+                inserted_platform.platform_id
+            );
+        }
+    }
+
+    fn initialize_enemies(
+        ctx: &ReducerContext,
+        definition: &SceneDefinition,
+        world_scene: &WorldScene,
+    ) -> Result<(), String> {
+        for setup in &definition.layout.enemies {
+            LootTable::find(ctx, setup.kind.stats().loot_table)?;
+
+            let patrol = match &setup.path {
+                Some(path) => path.waypoints.clone(),
+                None => vec![setup.position.clone()],
+            };
+
+            let inserted_enemy = ctx.db.enemy().insert(Enemy::new(
+                world_scene.scene_id,
+                setup.name.clone(),
+                setup.kind,
+                setup.position.clone(),
+                patrol,
+            ));
+
+            log::info!(
+                "Enemy {} ({:?}) registered with id: {}",
+                inserted_enemy.name,
+                inserted_enemy.kind,
+                inserted_enemy.enemy_id
+            );
+        }
+
+        Ok(())
+    }
+
+    fn initialize_checkpoints(
+        ctx: &ReducerContext,
+        definition: &SceneDefinition,
+        world_scene: &WorldScene,
+    ) {
+        for setup in &definition.layout.checkpoints {
+            let inserted_checkpoint = ctx.db.checkpoint().insert(Checkpoint {
+                checkpoint_id: 0,
+                scene_id: world_scene.scene_id,
+                name: setup.name.clone(),
+                position: setup.position.clone(),
+            });
+
+            log::info!(
+                "Checkpoint {} registered with id: {}",
+                inserted_checkpoint.name,
+                inserted_checkpoint.checkpoint_id
+            );
+        }
+    }
+
+    fn initialize_chests(
+        ctx: &ReducerContext,
+        definition: &SceneDefinition,
+        world_scene: &WorldScene,
+    ) -> Result<(), String> {
+        for setup in &definition.layout.chests {
+            LootTable::find(ctx, &setup.loot_table)?;
+
+            let inserted_chest = ctx.db.chest().insert(Chest {
+                chest_id: 0,
+                scene_id: world_scene.scene_id,
+                name: setup.name.clone(),
+                position: setup.position.clone(),
+                loot_table: setup.loot_table.clone(),
+            });
+
+            log::info!(
+                "Chest {} registered with id: {}",
+                inserted_chest.name,
+                inserted_chest.chest_id
+            );
+        }
+
+        Ok(())
+    }
 }